@@ -4,15 +4,22 @@ use soroban_sdk::{Address, Env, Map, String};
 use crate::errors::RentalError;
 use crate::events;
 use crate::storage::DataKey;
-use crate::types::{AgreementStatus, PaymentSplit, RentAgreement};
+use crate::types::{AccountStatement, AgreementStatus, ContractState, PaymentSplit, RentAgreement};
 
 const TTL_THRESHOLD: u32 = 500000;
 const TTL_BUMP: u32 = 500000;
 
+/// Basis-point denominator used for commission and fee rates (100% = 10,000 bps)
+pub const MAX_BPS: u32 = 10_000;
+
+/// Length of one rent period in seconds (30 days)
+pub const PAYMENT_PERIOD: u64 = 2_592_000;
+
 /// Validate agreement parameters
 ///
 /// Ensures monthly_rent is strictly positive (i128 > 0) to prevent logical errors
-/// in payment calculations and splits.
+/// in payment calculations and splits. `agent_commission_rate` is in basis points and,
+/// together with the platform `fee_bps`, may not exceed 100% of the rent.
 pub fn validate_agreement_params(
     env: &Env,
     monthly_rent: &i128,
@@ -35,13 +42,26 @@ pub fn validate_agreement_params(
         return Err(RentalError::InvalidDate);
     }
 
-    if *agent_commission_rate > 100 {
+    if *agent_commission_rate > MAX_BPS {
+        return Err(RentalError::InvalidCommissionRate);
+    }
+
+    if *agent_commission_rate + platform_fee_bps(env) > MAX_BPS {
         return Err(RentalError::InvalidCommissionRate);
     }
 
     Ok(())
 }
 
+/// Platform fee currently configured, in basis points; zero before initialization.
+///
+/// Agreements copy this rate when they are created, so a later `update_config` only
+/// changes the fee charged on agreements created after it.
+pub(crate) fn platform_fee_bps(env: &Env) -> u32 {
+    let state: Option<ContractState> = env.storage().instance().get(&DataKey::State);
+    state.map(|state| state.config.fee_bps).unwrap_or(0)
+}
+
 /// Create a new rent agreement
 #[allow(clippy::too_many_arguments)]
 pub fn create_agreement(
@@ -70,26 +90,18 @@ pub fn create_agreement(
         &agent_commission_rate,
     )?;
 
-    // Check for duplicate agreement_id
-    if env
-        .storage()
-        .persistent()
-        .has(&DataKey::Agreement(agreement_id.clone()))
-    {
-        return Err(RentalError::AgreementAlreadyExists);
-    }
-
     // Initialize agreement
     let agreement = RentAgreement {
-        agreement_id: agreement_id.clone(),
-        landlord: landlord.clone(),
-        tenant: tenant.clone(),
-        agent: agent.clone(),
+        agreement_id,
+        landlord,
+        tenant,
+        agent,
         monthly_rent,
         security_deposit,
         start_date,
         end_date,
         agent_commission_rate,
+        platform_fee_bps: platform_fee_bps(env),
         status: AgreementStatus::Draft,
         total_rent_paid: 0,
         payment_count: 0,
@@ -97,8 +109,33 @@ pub fn create_agreement(
         payment_token,
         next_payment_due: start_date,
         payment_history: Map::new(env),
+        late_fee_bps: 0,
+        late_fee_grace_period: 0,
+        terms_hash: None,
+        template_id: None,
+        deposit_released_at: None,
+        holdover_rent_bps: 0,
+        holdover_notice_period: 0,
+        holdover_started_at: None,
+        holdover_ends_at: None,
     };
 
+    store_new_agreement(env, agreement)
+}
+
+/// Persist a freshly built Draft agreement, bump the counter and emit the creation event
+pub(crate) fn store_new_agreement(env: &Env, agreement: RentAgreement) -> Result<(), RentalError> {
+    let agreement_id = agreement.agreement_id.clone();
+
+    // Check for duplicate agreement_id
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Agreement(agreement_id.clone()))
+    {
+        return Err(RentalError::AgreementAlreadyExists);
+    }
+
     // Store agreement
     env.storage()
         .persistent()
@@ -125,13 +162,13 @@ pub fn create_agreement(
     events::agreement_created(
         env,
         agreement_id,
-        tenant,
-        landlord,
-        monthly_rent,
-        security_deposit,
-        start_date,
-        end_date,
-        agent,
+        agreement.tenant,
+        agreement.landlord,
+        agreement.monthly_rent,
+        agreement.security_deposit,
+        agreement.start_date,
+        agreement.end_date,
+        agreement.agent,
     );
 
     Ok(())
@@ -309,3 +346,281 @@ pub fn get_payment_split(
         .get(month)
         .ok_or(RentalError::AgreementNotFound)
 }
+
+/// Record a rent payment collected by the configured payment contract.
+///
+/// The payment contract validates the payer and moves the funds; this stores the
+/// split against the next unpaid period and advances the agreement's totals and
+/// next due date. Only the payment contract may call it, and only during the term
+/// or a running holdover.
+pub fn record_payment(
+    env: &Env,
+    agreement_id: String,
+    payer: Address,
+    landlord_amount: i128,
+    agent_amount: i128,
+    platform_amount: i128,
+) -> Result<(), RentalError> {
+    let payment_contract: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::PaymentContract)
+        .ok_or(RentalError::PaymentContractNotSet)?;
+    payment_contract.require_auth();
+
+    let mut agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    let now = env.ledger().timestamp();
+    if !accepts_payments(&agreement, now) {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if landlord_amount < 0 || agent_amount < 0 || platform_amount < 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let amount = landlord_amount + agent_amount + platform_amount;
+    if amount != amount_due(&agreement, now) {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let period = agreement.payment_history.len();
+    agreement.payment_history.set(
+        period,
+        PaymentSplit {
+            landlord_amount,
+            agent_amount,
+            platform_amount,
+            token: agreement.payment_token.clone(),
+            payment_date: now,
+            payer,
+        },
+    );
+    agreement.total_rent_paid += amount;
+    agreement.payment_count += 1;
+    agreement.next_payment_due = period_due_date(&agreement, u64::from(period) + 1);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    events::rent_payment_recorded(
+        env,
+        agreement_id,
+        agreement.tenant,
+        agreement.landlord,
+        period,
+        amount,
+        now,
+    );
+
+    Ok(())
+}
+
+/// Rent payable each period while the agreement is in holdover
+pub fn holdover_rent(agreement: &RentAgreement) -> i128 {
+    if agreement.holdover_rent_bps == 0 {
+        return agreement.monthly_rent;
+    }
+    agreement.monthly_rent * agreement.holdover_rent_bps as i128 / MAX_BPS as i128
+}
+
+/// Rent payable for the next unpaid period: the monthly rent for a period of the
+/// term, the holdover rent for a period after it, whatever the agreement's status
+pub fn current_rent(agreement: &RentAgreement) -> i128 {
+    period_rent(agreement, u64::from(agreement.payment_history.len()))
+}
+
+/// Whether rent can be paid at `now`: during the term while Active, and while held
+/// over until the holdover ends
+fn accepts_payments(agreement: &RentAgreement, now: u64) -> bool {
+    match agreement.status {
+        AgreementStatus::Active => now <= agreement.end_date,
+        AgreementStatus::Holdover => agreement
+            .holdover_ends_at
+            .is_none_or(|ends_at| now < ends_at),
+        _ => false,
+    }
+}
+
+/// Late fee owed on the next payment if it is made at `now`: `late_fee_bps` of the
+/// current rent once `late_fee_grace_period` seconds have passed since it fell due
+pub fn late_fee(agreement: &RentAgreement, now: u64) -> i128 {
+    if now
+        > agreement
+            .next_payment_due
+            .saturating_add(agreement.late_fee_grace_period)
+    {
+        late_fee_on(agreement, current_rent(agreement))
+    } else {
+        0
+    }
+}
+
+/// Amount the next payment must total if it is made at `now`: the current rent
+/// plus any late fee
+pub fn amount_due(agreement: &RentAgreement, now: u64) -> i128 {
+    current_rent(agreement) + late_fee(agreement, now)
+}
+
+fn late_fee_on(agreement: &RentAgreement, rent: i128) -> i128 {
+    rent * agreement.late_fee_bps as i128 / MAX_BPS as i128
+}
+
+/// Number of rent periods in the fixed term between `start_date` and `end_date`
+fn term_periods(agreement: &RentAgreement) -> u64 {
+    agreement
+        .end_date
+        .saturating_sub(agreement.start_date)
+        .div_ceil(PAYMENT_PERIOD)
+}
+
+/// Rent charged for period `period` of the statement: the monthly rent during the
+/// term, the holdover rent after it
+fn period_rent(agreement: &RentAgreement, period: u64) -> i128 {
+    if period < term_periods(agreement) {
+        agreement.monthly_rent
+    } else {
+        holdover_rent(agreement)
+    }
+}
+
+/// Start of the first holdover period: the end of the last term period, which is
+/// `end_date` itself unless the term is not a whole number of periods
+fn holdover_start(agreement: &RentAgreement) -> u64 {
+    agreement.start_date + term_periods(agreement) * PAYMENT_PERIOD
+}
+
+/// Due date of rent period `period`; holdover periods run on from `holdover_start`
+fn period_due_date(agreement: &RentAgreement, period: u64) -> u64 {
+    let term_periods = term_periods(agreement);
+    if period < term_periods {
+        agreement.start_date + period * PAYMENT_PERIOD
+    } else {
+        holdover_start(agreement) + (period - term_periods) * PAYMENT_PERIOD
+    }
+}
+
+/// Number of holdover periods that have fallen due by `now`
+fn holdover_periods_due(agreement: &RentAgreement, now: u64) -> u64 {
+    let holdover_start = holdover_start(agreement);
+    if agreement.holdover_started_at.is_none() || now < holdover_start {
+        return 0;
+    }
+
+    match agreement.holdover_ends_at {
+        Some(ends_at) if now >= ends_at => ends_at
+            .saturating_sub(holdover_start)
+            .div_ceil(PAYMENT_PERIOD),
+        _ => (now - holdover_start) / PAYMENT_PERIOD + 1,
+    }
+}
+
+/// Build the account statement of an agreement as of the current ledger time.
+///
+/// Rent falls due at the start of every `PAYMENT_PERIOD` between `start_date` and
+/// `end_date`, then every period after the last term period at the holdover rent
+/// while the agreement is held over. Payment record `n` settles period `n`, and counts as
+/// late when it was made more than `late_fee_grace_period` seconds after that
+/// period's due date; the late fee on that period's rent is then added to the
+/// amount due. Periods still unpaid past their grace period add their late fee as well.
+pub fn get_statement(env: &Env, agreement_id: String) -> Result<AccountStatement, RentalError> {
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    let now = env.ledger().timestamp();
+
+    let total_periods = term_periods(&agreement);
+
+    let periods_due = if agreement.signed_at.is_none() || now < agreement.start_date {
+        0
+    } else {
+        ((now - agreement.start_date) / PAYMENT_PERIOD + 1).min(total_periods)
+    };
+    let holdover_periods = holdover_periods_due(&agreement, now);
+
+    let mut total_paid: i128 = 0;
+    let mut on_time_payments: u32 = 0;
+    let mut late_payments: u32 = 0;
+    let mut late_fees: i128 = 0;
+
+    for (period, split) in agreement.payment_history.iter() {
+        total_paid += split.landlord_amount + split.agent_amount + split.platform_amount;
+
+        let due_date = period_due_date(&agreement, u64::from(period));
+        if split.payment_date > due_date.saturating_add(agreement.late_fee_grace_period) {
+            late_payments += 1;
+            late_fees += late_fee_on(&agreement, period_rent(&agreement, u64::from(period)));
+        } else {
+            on_time_payments += 1;
+        }
+    }
+
+    // Unpaid periods already past their grace period owe their late fee too, as
+    // `amount_due` would charge it if they were paid now
+    let periods_paid = u64::from(agreement.payment_history.len());
+    for period in periods_paid..periods_due + holdover_periods {
+        let due_date = period_due_date(&agreement, period);
+        if now > due_date.saturating_add(agreement.late_fee_grace_period) {
+            late_fees += late_fee_on(&agreement, period_rent(&agreement, period));
+        }
+    }
+
+    let total_due = agreement.monthly_rent * periods_due as i128
+        + holdover_rent(&agreement) * holdover_periods as i128
+        + late_fees;
+    let outstanding_balance = (total_due - total_paid).max(0);
+
+    let next_due_date = match agreement.status {
+        AgreementStatus::Active if periods_paid < total_periods => {
+            Some(period_due_date(&agreement, periods_paid))
+        }
+        AgreementStatus::Holdover => {
+            let due_date = period_due_date(&agreement, periods_paid);
+            match agreement.holdover_ends_at {
+                Some(ends_at) if due_date >= ends_at => None,
+                _ => Some(due_date),
+            }
+        }
+        _ => None,
+    };
+    // Quote what `record_payment` will accept for the next payment
+    let next_due_amount = match next_due_date {
+        Some(_) => amount_due(&agreement, now),
+        None => 0,
+    };
+
+    let deposit_held = match agreement.status {
+        AgreementStatus::Active | AgreementStatus::Holdover | AgreementStatus::Disputed
+            if agreement.deposit_released_at.is_none() =>
+        {
+            agreement.security_deposit
+        }
+        _ => 0,
+    };
+
+    Ok(AccountStatement {
+        agreement_id,
+        total_due,
+        total_paid,
+        outstanding_balance,
+        next_due_date,
+        next_due_amount,
+        deposit_held,
+        on_time_payments,
+        late_payments,
+        as_of: now,
+    })
+}
@@ -0,0 +1,220 @@
+//! Move-in/move-out condition reports and the deposit release gate they control.
+use soroban_sdk::{Address, Env, String};
+
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, ConditionReport, RentAgreement, ReportKind};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Seconds a move-out report may stay unacknowledged before it stops blocking
+/// deposit release (14 days)
+pub const REPORT_ACK_TIMEOUT: u64 = 1_209_600;
+
+fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+fn save_report(env: &Env, report: &ConditionReport) {
+    let key = DataKey::ConditionReport(report.agreement_id.clone(), report.kind.clone());
+    env.storage().persistent().set(&key, report);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Attach an inspection report to an agreement.
+/// The submitting party is recorded as having acknowledged it.
+pub fn submit_condition_report(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+    kind: ReportKind,
+    report_hash: String,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    let is_landlord = caller == agreement.landlord;
+    let is_tenant = caller == agreement.tenant;
+    if !is_landlord && !is_tenant {
+        return Err(RentalError::Unauthorized);
+    }
+
+    // Move-in is inspected around signing, move-out once the tenancy is live or over
+    let allowed = match kind {
+        ReportKind::MoveIn => matches!(
+            agreement.status,
+            AgreementStatus::Pending | AgreementStatus::Active
+        ),
+        ReportKind::MoveOut => matches!(
+            agreement.status,
+            AgreementStatus::Active
+                | AgreementStatus::Holdover
+                | AgreementStatus::Completed
+                | AgreementStatus::Terminated
+        ),
+    };
+    if !allowed {
+        return Err(RentalError::InvalidState);
+    }
+
+    if report_hash.is_empty() {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    let key = DataKey::ConditionReport(agreement_id.clone(), kind.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(RentalError::ReportAlreadyExists);
+    }
+
+    let report = ConditionReport {
+        agreement_id: agreement_id.clone(),
+        kind: kind.clone(),
+        report_hash: report_hash.clone(),
+        submitted_by: caller.clone(),
+        submitted_at: env.ledger().timestamp(),
+        landlord_acknowledged: is_landlord,
+        tenant_acknowledged: is_tenant,
+        acknowledged_at: None,
+    };
+    save_report(env, &report);
+
+    events::condition_report_submitted(env, agreement_id, caller, kind, report_hash);
+
+    Ok(())
+}
+
+/// Acknowledge the other party's inspection report.
+pub fn acknowledge_condition_report(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+    kind: ReportKind,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    let mut report = get_condition_report(env, agreement_id.clone(), kind.clone())
+        .ok_or(RentalError::ReportNotFound)?;
+
+    if report.acknowledged_at.is_some() {
+        return Err(RentalError::InvalidState);
+    }
+
+    // The submitter acknowledged on submission; only the counterparty is left
+    if caller == report.submitted_by {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if caller == agreement.landlord {
+        report.landlord_acknowledged = true;
+    } else if caller == agreement.tenant {
+        report.tenant_acknowledged = true;
+    } else {
+        return Err(RentalError::Unauthorized);
+    }
+
+    let now = env.ledger().timestamp();
+    if report.landlord_acknowledged && report.tenant_acknowledged {
+        report.acknowledged_at = Some(now);
+    }
+    save_report(env, &report);
+
+    events::condition_report_acknowledged(env, agreement_id, caller, kind, now);
+
+    Ok(())
+}
+
+/// Retrieve a condition report of the given kind for an agreement
+pub fn get_condition_report(
+    env: &Env,
+    agreement_id: String,
+    kind: ReportKind,
+) -> Option<ConditionReport> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConditionReport(agreement_id, kind))
+}
+
+/// Whether the security deposit may be released now.
+///
+/// Release is blocked while a move-out report exists that both parties have not
+/// acknowledged, until `REPORT_ACK_TIMEOUT` has passed since it was submitted.
+pub fn can_release_deposit(env: &Env, agreement_id: String) -> Result<bool, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.deposit_released_at.is_some() {
+        return Ok(false);
+    }
+
+    match get_condition_report(env, agreement_id, ReportKind::MoveOut) {
+        Some(report) if report.acknowledged_at.is_none() => {
+            Ok(env.ledger().timestamp() >= report.submitted_at.saturating_add(REPORT_ACK_TIMEOUT))
+        }
+        _ => Ok(true),
+    }
+}
+
+/// Record that the landlord has released the security deposit
+pub fn release_deposit(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if !matches!(
+        agreement.status,
+        AgreementStatus::Active
+            | AgreementStatus::Holdover
+            | AgreementStatus::Completed
+            | AgreementStatus::Terminated
+    ) {
+        return Err(RentalError::InvalidState);
+    }
+
+    if agreement.deposit_released_at.is_some() {
+        return Err(RentalError::DepositAlreadyReleased);
+    }
+
+    if !can_release_deposit(env, agreement_id.clone())? {
+        return Err(RentalError::ReportUnacknowledged);
+    }
+
+    let now = env.ledger().timestamp();
+    agreement.deposit_released_at = Some(now);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    events::deposit_released(
+        env,
+        agreement_id,
+        landlord,
+        agreement.tenant,
+        agreement.security_deposit,
+        now,
+    );
+
+    Ok(())
+}
@@ -18,4 +18,15 @@ pub enum RentalError {
     InvalidState = 15,
     Expired = 16,
     ContractPaused = 17,
+    TemplateNotFound = 19,
+    TemplateAlreadyExists = 20,
+    TemplateInactive = 21,
+    ReportAlreadyExists = 22,
+    ReportNotFound = 23,
+    ReportUnacknowledged = 24,
+    DepositAlreadyReleased = 25,
+    NoHoldoverClause = 26,
+    NoticeAlreadyGiven = 27,
+    NoticePeriodNotElapsed = 28,
+    PaymentContractNotSet = 29,
 }
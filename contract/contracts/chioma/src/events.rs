@@ -1,4 +1,4 @@
-use crate::Config;
+use crate::{Config, ReportKind};
 use soroban_sdk::{contractevent, Address, Env, String};
 
 /// Event emitted when the contract is initialized
@@ -76,6 +76,135 @@ pub struct ConfigUpdated {
     pub new_paused: bool,
 }
 
+/// Event emitted when the admin changes the payment contract allowed to record rent
+/// Topics: ["pay_set", admin: Address]
+#[contractevent(topics = ["pay_set"])]
+pub struct PaymentContractSet {
+    #[topic]
+    pub admin: Address,
+    pub old_contract: Option<Address>,
+    pub new_contract: Address,
+}
+
+/// Event emitted when a landlord registers an agreement template
+/// Topics: ["tpl_created", landlord: Address]
+#[contractevent(topics = ["tpl_created"])]
+pub struct TemplateCreated {
+    #[topic]
+    pub landlord: Address,
+    pub template_id: String,
+    pub terms_hash: String,
+}
+
+/// Event emitted when a landlord deactivates an agreement template
+/// Topics: ["tpl_deactivated", landlord: Address]
+#[contractevent(topics = ["tpl_deactivated"])]
+pub struct TemplateDeactivated {
+    #[topic]
+    pub landlord: Address,
+    pub template_id: String,
+}
+
+/// Event emitted when a condition report is attached to an agreement
+/// Topics: ["report_submit", agreement_id: String, submitted_by: Address]
+#[contractevent(topics = ["report_submit"])]
+pub struct ConditionReportSubmitted {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub submitted_by: Address,
+    pub kind: ReportKind,
+    pub report_hash: String,
+}
+
+/// Event emitted when a party acknowledges a condition report
+/// Topics: ["report_ack", agreement_id: String, acknowledged_by: Address]
+#[contractevent(topics = ["report_ack"])]
+pub struct ConditionReportAcknowledged {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub acknowledged_by: Address,
+    pub kind: ReportKind,
+    pub acknowledged_at: u64,
+}
+
+/// Event emitted when the landlord releases the security deposit
+/// Topics: ["deposit_released", landlord: Address, tenant: Address]
+#[contractevent(topics = ["deposit_released"])]
+pub struct DepositReleased {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+    pub released_at: u64,
+}
+
+/// Event emitted when the payment contract records a rent payment
+/// Topics: ["rent_paid", tenant: Address, landlord: Address]
+#[contractevent(topics = ["rent_paid"])]
+pub struct RentPaymentRecorded {
+    #[topic]
+    pub tenant: Address,
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub period: u32,
+    pub amount: i128,
+    pub paid_at: u64,
+}
+
+/// Event emitted when a landlord attaches a holdover clause to an agreement
+/// Topics: ["holdover_clause", landlord: Address, tenant: Address]
+#[contractevent(topics = ["holdover_clause"])]
+pub struct HoldoverClauseSet {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub rent_multiplier_bps: u32,
+    pub notice_period: u64,
+}
+
+/// Event emitted when an expired agreement rolls over into holdover
+/// Topics: ["holdover_start", landlord: Address, tenant: Address]
+#[contractevent(topics = ["holdover_start"])]
+pub struct HoldoverStarted {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub holdover_rent: i128,
+    pub started_at: u64,
+}
+
+/// Event emitted when a party gives notice to end a holdover
+/// Topics: ["holdover_notice", agreement_id: String, given_by: Address]
+#[contractevent(topics = ["holdover_notice"])]
+pub struct HoldoverNoticeGiven {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub given_by: Address,
+    pub ends_at: u64,
+}
+
+/// Event emitted when a holdover ends after its notice period
+/// Topics: ["holdover_end", landlord: Address, tenant: Address]
+#[contractevent(topics = ["holdover_end"])]
+pub struct HoldoverEnded {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub ended_at: u64,
+}
+
 /// Helper function to emit contract initialized event
 pub(crate) fn contract_initialized(env: &Env, admin: Address, config: Config) {
     ContractInitialized {
@@ -173,3 +302,186 @@ pub(crate) fn config_updated(env: &Env, admin: Address, old_config: Config, new_
     }
     .publish(env);
 }
+
+/// Helper function to emit payment contract changed event
+pub(crate) fn payment_contract_set(
+    env: &Env,
+    admin: Address,
+    old_contract: Option<Address>,
+    new_contract: Address,
+) {
+    PaymentContractSet {
+        admin,
+        old_contract,
+        new_contract,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit template created event
+pub(crate) fn template_created(
+    env: &Env,
+    template_id: String,
+    landlord: Address,
+    terms_hash: String,
+) {
+    TemplateCreated {
+        landlord,
+        template_id,
+        terms_hash,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit template deactivated event
+pub(crate) fn template_deactivated(env: &Env, template_id: String, landlord: Address) {
+    TemplateDeactivated {
+        landlord,
+        template_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit condition report submitted event
+pub(crate) fn condition_report_submitted(
+    env: &Env,
+    agreement_id: String,
+    submitted_by: Address,
+    kind: ReportKind,
+    report_hash: String,
+) {
+    ConditionReportSubmitted {
+        agreement_id,
+        submitted_by,
+        kind,
+        report_hash,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit condition report acknowledged event
+pub(crate) fn condition_report_acknowledged(
+    env: &Env,
+    agreement_id: String,
+    acknowledged_by: Address,
+    kind: ReportKind,
+    acknowledged_at: u64,
+) {
+    ConditionReportAcknowledged {
+        agreement_id,
+        acknowledged_by,
+        kind,
+        acknowledged_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit deposit released event
+pub(crate) fn deposit_released(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    amount: i128,
+    released_at: u64,
+) {
+    DepositReleased {
+        landlord,
+        tenant,
+        agreement_id,
+        amount,
+        released_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit holdover clause set event
+pub(crate) fn holdover_clause_set(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    rent_multiplier_bps: u32,
+    notice_period: u64,
+) {
+    HoldoverClauseSet {
+        landlord,
+        tenant,
+        agreement_id,
+        rent_multiplier_bps,
+        notice_period,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit holdover started event
+pub(crate) fn holdover_started(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    holdover_rent: i128,
+    started_at: u64,
+) {
+    HoldoverStarted {
+        landlord,
+        tenant,
+        agreement_id,
+        holdover_rent,
+        started_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit holdover notice event
+pub(crate) fn holdover_notice_given(
+    env: &Env,
+    agreement_id: String,
+    given_by: Address,
+    ends_at: u64,
+) {
+    HoldoverNoticeGiven {
+        agreement_id,
+        given_by,
+        ends_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit holdover ended event
+pub(crate) fn holdover_ended(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    ended_at: u64,
+) {
+    HoldoverEnded {
+        landlord,
+        tenant,
+        agreement_id,
+        ended_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent payment recorded event
+pub(crate) fn rent_payment_recorded(
+    env: &Env,
+    agreement_id: String,
+    tenant: Address,
+    landlord: Address,
+    period: u32,
+    amount: i128,
+    paid_at: u64,
+) {
+    RentPaymentRecorded {
+        tenant,
+        landlord,
+        agreement_id,
+        period,
+        amount,
+        paid_at,
+    }
+    .publish(env);
+}
@@ -0,0 +1,179 @@
+//! Holdover handling: continuing an expired agreement as a periodic tenancy.
+use soroban_sdk::{Address, Env, String};
+
+use crate::agreement::holdover_rent;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+fn save_agreement(env: &Env, agreement: &RentAgreement) {
+    let key = DataKey::Agreement(agreement.agreement_id.clone());
+    env.storage().persistent().set(&key, agreement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+fn require_party(agreement: &RentAgreement, caller: &Address) -> Result<(), RentalError> {
+    if *caller != agreement.landlord && *caller != agreement.tenant {
+        return Err(RentalError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Attach a holdover clause to a Draft agreement (landlord only).
+/// The tenant accepts it by signing the agreement.
+pub fn set_holdover_clause(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+    rent_multiplier_bps: u32,
+    notice_period: u64,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Draft {
+        return Err(RentalError::InvalidState);
+    }
+
+    if rent_multiplier_bps == 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    if notice_period == 0 {
+        return Err(RentalError::InvalidDate);
+    }
+
+    agreement.holdover_rent_bps = rent_multiplier_bps;
+    agreement.holdover_notice_period = notice_period;
+    save_agreement(env, &agreement);
+
+    events::holdover_clause_set(
+        env,
+        agreement_id,
+        landlord,
+        agreement.tenant,
+        rent_multiplier_bps,
+        notice_period,
+    );
+
+    Ok(())
+}
+
+/// Roll an expired Active agreement into holdover (Active → Holdover).
+/// Either party may trigger it once `end_date` has passed.
+pub fn start_holdover(env: &Env, caller: Address, agreement_id: String) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    require_party(&agreement, &caller)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::InvalidState);
+    }
+
+    if agreement.holdover_rent_bps == 0 {
+        return Err(RentalError::NoHoldoverClause);
+    }
+
+    let now = env.ledger().timestamp();
+    if now <= agreement.end_date {
+        return Err(RentalError::InvalidDate);
+    }
+
+    agreement.status = AgreementStatus::Holdover;
+    agreement.holdover_started_at = Some(now);
+    save_agreement(env, &agreement);
+
+    events::holdover_started(
+        env,
+        agreement_id,
+        agreement.landlord.clone(),
+        agreement.tenant.clone(),
+        holdover_rent(&agreement),
+        now,
+    );
+
+    Ok(())
+}
+
+/// Give notice to end the holdover after the clause's notice period.
+pub fn give_holdover_notice(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    require_party(&agreement, &caller)?;
+
+    if agreement.status != AgreementStatus::Holdover {
+        return Err(RentalError::InvalidState);
+    }
+
+    if agreement.holdover_ends_at.is_some() {
+        return Err(RentalError::NoticeAlreadyGiven);
+    }
+
+    let ends_at = env
+        .ledger()
+        .timestamp()
+        .saturating_add(agreement.holdover_notice_period);
+
+    agreement.holdover_ends_at = Some(ends_at);
+    save_agreement(env, &agreement);
+
+    events::holdover_notice_given(env, agreement_id, caller, ends_at);
+
+    Ok(())
+}
+
+/// Close a holdover whose notice period has run out (Holdover → Completed).
+pub fn end_holdover(env: &Env, caller: Address, agreement_id: String) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    require_party(&agreement, &caller)?;
+
+    if agreement.status != AgreementStatus::Holdover {
+        return Err(RentalError::InvalidState);
+    }
+
+    let ends_at = agreement
+        .holdover_ends_at
+        .ok_or(RentalError::NoticePeriodNotElapsed)?;
+    if env.ledger().timestamp() < ends_at {
+        return Err(RentalError::NoticePeriodNotElapsed);
+    }
+
+    agreement.status = AgreementStatus::Completed;
+    save_agreement(env, &agreement);
+
+    events::holdover_ended(
+        env,
+        agreement_id,
+        agreement.landlord.clone(),
+        agreement.tenant.clone(),
+        ends_at,
+    );
+
+    Ok(())
+}
@@ -4,21 +4,36 @@
 use soroban_sdk::{contract, contractimpl, Address, Env, String};
 
 mod agreement;
+mod condition;
 mod errors;
 mod events;
+mod holdover;
 mod storage;
+mod template;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
 pub use agreement::{
-    cancel_agreement, create_agreement, get_agreement, get_agreement_count, get_payment_split,
-    has_agreement, sign_agreement, submit_agreement, validate_agreement_params,
+    amount_due, cancel_agreement, create_agreement, current_rent, get_agreement,
+    get_agreement_count, get_payment_split, get_statement, has_agreement, record_payment,
+    sign_agreement, submit_agreement, validate_agreement_params,
+};
+pub use condition::{
+    acknowledge_condition_report, can_release_deposit, get_condition_report, release_deposit,
+    submit_condition_report,
 };
 pub use errors::RentalError;
+pub use holdover::{end_holdover, give_holdover_notice, set_holdover_clause, start_holdover};
 pub use storage::DataKey;
-pub use types::{AgreementStatus, Config, ContractState, PaymentSplit, RentAgreement};
+pub use template::{
+    create_agreement_from_template, create_template, deactivate_template, get_template,
+};
+pub use types::{
+    AccountStatement, AgreementStatus, AgreementTemplate, ConditionReport, Config, ContractState,
+    PaymentSplit, RentAgreement, ReportKind, TemplateOverrides,
+};
 
 #[contract]
 pub struct Contract;
@@ -121,6 +136,45 @@ impl Contract {
         Ok(())
     }
 
+    /// Set the payment contract allowed to record rent payments (admin only).
+    /// Emits the previous and new address.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `payment_contract` - Address of the payment contract
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if set, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    pub fn set_payment_contract(env: Env, payment_contract: Address) -> Result<(), RentalError> {
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+
+        state.admin.require_auth();
+
+        let old_contract: Option<Address> = env.storage().instance().get(&DataKey::PaymentContract);
+        env.storage()
+            .instance()
+            .set(&DataKey::PaymentContract, &payment_contract);
+        env.storage().instance().extend_ttl(500000, 500000);
+
+        events::payment_contract_set(&env, state.admin, old_contract, payment_contract);
+
+        Ok(())
+    }
+
+    /// Get the payment contract allowed to record rent payments.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The payment contract if configured, otherwise None
+    pub fn get_payment_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PaymentContract)
+    }
+
     /// Create a new rental agreement.
     ///
     /// # Arguments
@@ -168,6 +222,122 @@ impl Contract {
         )
     }
 
+    /// Register a reusable agreement template owned by a landlord.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `template_id` - Identifier for the template, unique among the landlord's templates
+    /// * `landlord` - Address of the property owner who owns the template
+    /// * `monthly_rent` - Default rent amount to be paid each month
+    /// * `security_deposit` - Default deposit amount held for security
+    /// * `term_length` - Default lease length in seconds
+    /// * `agent_commission_rate` - Default commission rate for the agent in basis points
+    /// * `payment_token` - The address of the token used for payments
+    /// * `late_fee_bps` - Late fee in basis points of the monthly rent
+    /// * `late_fee_grace_period` - Seconds after a due date before a payment is late
+    /// * `terms_hash` - Hash of the off-chain standard terms document
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if registered, otherwise an error
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_template(
+        env: Env,
+        template_id: String,
+        landlord: Address,
+        monthly_rent: i128,
+        security_deposit: i128,
+        term_length: u64,
+        agent_commission_rate: u32,
+        payment_token: Address,
+        late_fee_bps: u32,
+        late_fee_grace_period: u64,
+        terms_hash: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        template::create_template(
+            &env,
+            template_id,
+            landlord,
+            monthly_rent,
+            security_deposit,
+            term_length,
+            agent_commission_rate,
+            payment_token,
+            late_fee_bps,
+            late_fee_grace_period,
+            terms_hash,
+        )
+    }
+
+    /// Deactivate a template so it can no longer be used for new agreements.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord owning the template
+    /// * `template_id` - The identifier of the template to deactivate
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if deactivated, otherwise an error
+    pub fn deactivate_template(
+        env: Env,
+        landlord: Address,
+        template_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        template::deactivate_template(&env, landlord, template_id)
+    }
+
+    /// Retrieve a landlord's registered agreement template.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord owning the template
+    /// * `template_id` - The identifier of the template
+    ///
+    /// # Returns
+    /// * `Option<AgreementTemplate>` - The template if found, otherwise None
+    pub fn get_template(
+        env: Env,
+        landlord: Address,
+        template_id: String,
+    ) -> Option<AgreementTemplate> {
+        template::get_template(&env, landlord, template_id)
+    }
+
+    /// Create a new rental agreement from a landlord's template.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - Unique identifier for the agreement
+    /// * `landlord` - The address of the landlord owning the template
+    /// * `template_id` - The identifier of the template to start from
+    /// * `tenant` - Address of the person renting the property
+    /// * `start_date` - Unix timestamp for the start of the lease
+    /// * `overrides` - Values replacing the template defaults for this agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if created, otherwise an error
+    pub fn create_agreement_from_template(
+        env: Env,
+        agreement_id: String,
+        landlord: Address,
+        template_id: String,
+        tenant: Address,
+        start_date: u64,
+        overrides: TemplateOverrides,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        template::create_agreement_from_template(
+            &env,
+            agreement_id,
+            landlord,
+            template_id,
+            tenant,
+            start_date,
+            overrides,
+        )
+    }
+
     /// Sign an existing rental agreement.
     ///
     /// # Arguments
@@ -273,4 +443,226 @@ impl Contract {
     ) -> Result<PaymentSplit, RentalError> {
         agreement::get_payment_split(&env, agreement_id, month)
     }
+
+    /// Record a rent payment on an agreement (payment contract only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `payer` - The address that paid the rent
+    /// * `landlord_amount` - Share paid to the landlord
+    /// * `agent_amount` - Share paid to the agent
+    /// * `platform_amount` - Share paid to the platform
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if recorded, otherwise an error
+    ///
+    /// # Errors
+    /// * `PaymentContractNotSet` - If no payment contract is configured
+    /// * `AgreementNotActive` - If the agreement is not Active or in Holdover
+    /// * `InvalidAmount` - If the shares do not add up to the rent currently due,
+    ///   including any late fee
+    pub fn record_payment(
+        env: Env,
+        agreement_id: String,
+        payer: Address,
+        landlord_amount: i128,
+        agent_amount: i128,
+        platform_amount: i128,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        agreement::record_payment(
+            &env,
+            agreement_id,
+            payer,
+            landlord_amount,
+            agent_amount,
+            platform_amount,
+        )
+    }
+
+    /// Get the tenant account statement for an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<AccountStatement, RentalError>` - Amounts due, paid and outstanding,
+    ///   the next due date, the deposit held and on-time versus late payment counts
+    pub fn get_statement(env: Env, agreement_id: String) -> Result<AccountStatement, RentalError> {
+        agreement::get_statement(&env, agreement_id)
+    }
+
+    /// Attach a move-in or move-out inspection report to an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The landlord or tenant submitting the report
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `kind` - Whether this is the move-in or move-out inspection
+    /// * `report_hash` - Content hash of the off-chain inspection report
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if attached, otherwise an error
+    pub fn submit_condition_report(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+        kind: ReportKind,
+        report_hash: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        condition::submit_condition_report(&env, caller, agreement_id, kind, report_hash)
+    }
+
+    /// Acknowledge a condition report submitted by the other party.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The landlord or tenant acknowledging the report
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `kind` - Whether this is the move-in or move-out inspection
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if acknowledged, otherwise an error
+    pub fn acknowledge_condition_report(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+        kind: ReportKind,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        condition::acknowledge_condition_report(&env, caller, agreement_id, kind)
+    }
+
+    /// Retrieve a condition report attached to an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `kind` - Whether to fetch the move-in or move-out inspection
+    ///
+    /// # Returns
+    /// * `Option<ConditionReport>` - The report if one was submitted, otherwise None
+    pub fn get_condition_report(
+        env: Env,
+        agreement_id: String,
+        kind: ReportKind,
+    ) -> Option<ConditionReport> {
+        condition::get_condition_report(&env, agreement_id, kind)
+    }
+
+    /// Check whether the security deposit of an agreement may be released now.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<bool, RentalError>` - False while a move-out report awaits acknowledgement
+    ///   and its timeout has not passed, or once the deposit was already released
+    pub fn can_release_deposit(env: Env, agreement_id: String) -> Result<bool, RentalError> {
+        condition::can_release_deposit(&env, agreement_id)
+    }
+
+    /// Record the release of the security deposit (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The landlord of the agreement
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if released, otherwise an error
+    pub fn release_deposit(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        condition::release_deposit(&env, landlord, agreement_id)
+    }
+
+    /// Attach a holdover clause to a Draft agreement (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The landlord of the agreement
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `rent_multiplier_bps` - Holdover rent as basis points of the monthly rent
+    /// * `notice_period` - Seconds of notice either party must give to end the holdover
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if set, otherwise an error
+    pub fn set_holdover_clause(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+        rent_multiplier_bps: u32,
+        notice_period: u64,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        holdover::set_holdover_clause(
+            &env,
+            landlord,
+            agreement_id,
+            rent_multiplier_bps,
+            notice_period,
+        )
+    }
+
+    /// Continue an expired Active agreement as a periodic tenancy (Active → Holdover).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The landlord or tenant of the agreement
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if the holdover started, otherwise an error
+    pub fn start_holdover(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        holdover::start_holdover(&env, caller, agreement_id)
+    }
+
+    /// Give notice to end a holdover after the clause's notice period.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The landlord or tenant of the agreement
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if notice was recorded, otherwise an error
+    pub fn give_holdover_notice(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        holdover::give_holdover_notice(&env, caller, agreement_id)
+    }
+
+    /// End a holdover once its notice period has elapsed (Holdover → Completed).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The landlord or tenant of the agreement
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if the holdover ended, otherwise an error
+    pub fn end_holdover(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_paused(&env)?;
+        holdover::end_holdover(&env, caller, agreement_id)
+    }
 }
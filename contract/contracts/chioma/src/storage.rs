@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, String};
+
+use crate::types::ReportKind;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,4 +9,7 @@ pub enum DataKey {
     AgreementCount,
     State,
     Initialized,
+    Template(Address, String),
+    ConditionReport(String, ReportKind),
+    PaymentContract,
 }
@@ -0,0 +1,193 @@
+//! Landlord-owned agreement templates for the Chioma/Rental contract.
+use soroban_sdk::{Address, Env, Map, String};
+
+use crate::agreement::{platform_fee_bps, store_new_agreement, validate_agreement_params, MAX_BPS};
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, AgreementTemplate, RentAgreement, TemplateOverrides};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Register a reusable agreement template owned by the landlord.
+///
+/// Template identifiers are scoped to their landlord, so two landlords may use the same one.
+#[allow(clippy::too_many_arguments)]
+pub fn create_template(
+    env: &Env,
+    template_id: String,
+    landlord: Address,
+    monthly_rent: i128,
+    security_deposit: i128,
+    term_length: u64,
+    agent_commission_rate: u32,
+    payment_token: Address,
+    late_fee_bps: u32,
+    late_fee_grace_period: u64,
+    terms_hash: String,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    if monthly_rent <= 0 || security_deposit < 0 || late_fee_bps > MAX_BPS {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    if term_length == 0 {
+        return Err(RentalError::InvalidDate);
+    }
+
+    if agent_commission_rate > MAX_BPS {
+        return Err(RentalError::InvalidCommissionRate);
+    }
+
+    if terms_hash.is_empty() {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    let key = DataKey::Template(landlord.clone(), template_id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(RentalError::TemplateAlreadyExists);
+    }
+
+    let template = AgreementTemplate {
+        template_id: template_id.clone(),
+        landlord: landlord.clone(),
+        monthly_rent,
+        security_deposit,
+        term_length,
+        agent_commission_rate,
+        payment_token,
+        late_fee_bps,
+        late_fee_grace_period,
+        terms_hash: terms_hash.clone(),
+        active: true,
+        created_at: env.ledger().timestamp(),
+    };
+
+    env.storage().persistent().set(&key, &template);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::template_created(env, template_id, landlord, terms_hash);
+
+    Ok(())
+}
+
+/// Stop a template from being used for new agreements
+pub fn deactivate_template(
+    env: &Env,
+    landlord: Address,
+    template_id: String,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let key = DataKey::Template(landlord.clone(), template_id.clone());
+    let mut template: AgreementTemplate = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(RentalError::TemplateNotFound)?;
+
+    if !template.active {
+        return Err(RentalError::TemplateInactive);
+    }
+
+    template.active = false;
+
+    env.storage().persistent().set(&key, &template);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::template_deactivated(env, template_id, landlord);
+
+    Ok(())
+}
+
+/// Retrieve a landlord's template by its identifier
+pub fn get_template(
+    env: &Env,
+    landlord: Address,
+    template_id: String,
+) -> Option<AgreementTemplate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Template(landlord, template_id))
+}
+
+/// Create a Draft agreement from a template, applying any overrides
+pub fn create_agreement_from_template(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    template_id: String,
+    tenant: Address,
+    start_date: u64,
+    overrides: TemplateOverrides,
+) -> Result<(), RentalError> {
+    // Tenant MUST authorize creation
+    tenant.require_auth();
+
+    let template =
+        get_template(env, landlord, template_id.clone()).ok_or(RentalError::TemplateNotFound)?;
+
+    if !template.active {
+        return Err(RentalError::TemplateInactive);
+    }
+
+    let monthly_rent = overrides.monthly_rent.unwrap_or(template.monthly_rent);
+    let security_deposit = overrides
+        .security_deposit
+        .unwrap_or(template.security_deposit);
+    let end_date = match overrides.end_date {
+        Some(end_date) => end_date,
+        None => start_date
+            .checked_add(template.term_length)
+            .ok_or(RentalError::InvalidDate)?,
+    };
+    let agent_commission_rate = overrides
+        .agent_commission_rate
+        .unwrap_or(template.agent_commission_rate);
+
+    validate_agreement_params(
+        env,
+        &monthly_rent,
+        &security_deposit,
+        &start_date,
+        &end_date,
+        &agent_commission_rate,
+    )?;
+
+    let agreement = RentAgreement {
+        agreement_id,
+        landlord: template.landlord,
+        tenant,
+        agent: overrides.agent,
+        monthly_rent,
+        security_deposit,
+        start_date,
+        end_date,
+        agent_commission_rate,
+        platform_fee_bps: platform_fee_bps(env),
+        status: AgreementStatus::Draft,
+        total_rent_paid: 0,
+        payment_count: 0,
+        signed_at: None,
+        payment_token: template.payment_token,
+        next_payment_due: start_date,
+        payment_history: Map::new(env),
+        late_fee_bps: template.late_fee_bps,
+        late_fee_grace_period: template.late_fee_grace_period,
+        terms_hash: Some(template.terms_hash),
+        template_id: Some(template_id),
+        deposit_released_at: None,
+        holdover_rent_bps: 0,
+        holdover_notice_period: 0,
+        holdover_started_at: None,
+        holdover_ends_at: None,
+    };
+
+    store_new_agreement(env, agreement)
+}
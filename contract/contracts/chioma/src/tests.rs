@@ -530,7 +530,7 @@ fn test_invalid_commission_rate() {
         &2000,
         &100,
         &200,
-        &101,
+        &10_001,
         &Address::generate(&env),
     );
 }
@@ -1038,7 +1038,7 @@ proptest! {
         security_deposit in -10000i128..10000i128,
         start_date in 0u64..10000u64,
         end_date in 0u64..10000u64,
-        agent_commission_rate in 0u32..200u32
+        agent_commission_rate in 0u32..20_000u32
     ) {
         let env = Env::default();
         env.mock_all_auths();
@@ -1066,7 +1066,7 @@ proptest! {
         let is_valid_rent = monthly_rent > 0;
         let is_valid_deposit = security_deposit >= 0;
         let is_valid_dates = start_date < end_date;
-        let is_valid_commission = agent_commission_rate <= 100;
+        let is_valid_commission = agent_commission_rate <= 10_000;
 
         let should_succeed = is_valid_rent && is_valid_deposit && is_valid_dates && is_valid_commission;
 
@@ -1172,3 +1172,1054 @@ fn test_contract_paused_operations() {
     let res_sign_success = client.try_sign_agreement(&tenant, &agreement_id);
     assert!(res_sign_success.is_ok());
 }
+
+fn create_test_template(
+    env: &Env,
+    client: &ContractClient<'_>,
+    template_id: &str,
+    landlord: &Address,
+    payment_token: &Address,
+) {
+    client.create_template(
+        &String::from_str(env, template_id),
+        landlord,
+        &1200,
+        &2400,
+        &31_536_000,
+        &5,
+        payment_token,
+        &500,
+        &432_000,
+        &String::from_str(env, "QmStandardLease"),
+    );
+}
+
+fn no_overrides() -> TemplateOverrides {
+    TemplateOverrides {
+        agent: None,
+        monthly_rent: None,
+        security_deposit: None,
+        end_date: None,
+        agent_commission_rate: None,
+    }
+}
+
+#[test]
+fn test_create_template_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    create_test_template(&env, &client, "TPL_001", &landlord, &payment_token);
+
+    let template = client
+        .get_template(&landlord, &String::from_str(&env, "TPL_001"))
+        .unwrap();
+    assert_eq!(template.landlord, landlord);
+    assert_eq!(template.monthly_rent, 1200);
+    assert_eq!(template.term_length, 31_536_000);
+    assert_eq!(template.payment_token, payment_token);
+    assert!(template.active);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_create_template_duplicate_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    create_test_template(&env, &client, "TPL_DUP", &landlord, &payment_token);
+    create_test_template(&env, &client, "TPL_DUP", &landlord, &payment_token);
+}
+
+#[test]
+fn test_template_ids_are_scoped_to_landlord() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let other = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let template_id = String::from_str(&env, "TPL_SHARED");
+
+    create_test_template(&env, &client, "TPL_SHARED", &landlord, &payment_token);
+    create_test_template(&env, &client, "TPL_SHARED", &other, &payment_token);
+    client.deactivate_template(&other, &template_id);
+
+    let template = client.get_template(&landlord, &template_id).unwrap();
+    assert_eq!(template.landlord, landlord);
+    assert!(template.active);
+    assert!(!client.get_template(&other, &template_id).unwrap().active);
+}
+
+#[test]
+fn test_create_agreement_from_template_uses_defaults() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    create_test_template(&env, &client, "TPL_002", &landlord, &payment_token);
+
+    let agreement_id = String::from_str(&env, "AGR_FROM_TPL");
+    client.create_agreement_from_template(
+        &agreement_id,
+        &landlord,
+        &String::from_str(&env, "TPL_002"),
+        &tenant,
+        &100,
+        &no_overrides(),
+    );
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.landlord, landlord);
+    assert_eq!(agreement.tenant, tenant);
+    assert_eq!(agreement.monthly_rent, 1200);
+    assert_eq!(agreement.security_deposit, 2400);
+    assert_eq!(agreement.end_date, 100 + 31_536_000);
+    assert_eq!(agreement.late_fee_bps, 500);
+    assert_eq!(agreement.status, AgreementStatus::Draft);
+    assert_eq!(
+        agreement.template_id,
+        Some(String::from_str(&env, "TPL_002"))
+    );
+    assert_eq!(
+        agreement.terms_hash,
+        Some(String::from_str(&env, "QmStandardLease"))
+    );
+    assert_eq!(client.get_agreement_count(), 1);
+}
+
+#[test]
+fn test_create_agreement_from_template_with_overrides() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    create_test_template(&env, &client, "TPL_003", &landlord, &payment_token);
+
+    let overrides = TemplateOverrides {
+        agent: Some(agent.clone()),
+        monthly_rent: Some(1500),
+        security_deposit: None,
+        end_date: Some(5000),
+        agent_commission_rate: Some(10),
+    };
+
+    let agreement_id = String::from_str(&env, "AGR_OVERRIDE");
+    client.create_agreement_from_template(
+        &agreement_id,
+        &landlord,
+        &String::from_str(&env, "TPL_003"),
+        &tenant,
+        &100,
+        &overrides,
+    );
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.agent, Some(agent));
+    assert_eq!(agreement.monthly_rent, 1500);
+    assert_eq!(agreement.security_deposit, 2400);
+    assert_eq!(agreement.end_date, 5000);
+    assert_eq!(agreement.agent_commission_rate, 10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_create_agreement_from_deactivated_template() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    create_test_template(&env, &client, "TPL_004", &landlord, &payment_token);
+    client.deactivate_template(&landlord, &String::from_str(&env, "TPL_004"));
+
+    client.create_agreement_from_template(
+        &String::from_str(&env, "AGR_INACTIVE"),
+        &landlord,
+        &String::from_str(&env, "TPL_004"),
+        &tenant,
+        &100,
+        &no_overrides(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_deactivate_template_of_other_landlord() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let other = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    create_test_template(&env, &client, "TPL_005", &landlord, &payment_token);
+    client.deactivate_template(&other, &String::from_str(&env, "TPL_005"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_create_agreement_from_missing_template() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+
+    client.create_agreement_from_template(
+        &String::from_str(&env, "AGR_MISSING"),
+        &Address::generate(&env),
+        &String::from_str(&env, "NO_SUCH_TPL"),
+        &tenant,
+        &100,
+        &no_overrides(),
+    );
+}
+
+fn record_test_payment(
+    env: &Env,
+    client: &ContractClient<'_>,
+    agreement_id: &str,
+    amount: i128,
+    payment_date: u64,
+) {
+    let id = String::from_str(env, agreement_id);
+    let mut agreement = client.get_agreement(&id).unwrap();
+    let month = agreement.payment_history.len();
+    agreement.payment_history.set(
+        month,
+        PaymentSplit {
+            landlord_amount: amount,
+            agent_amount: 0,
+            platform_amount: 0,
+            token: agreement.payment_token.clone(),
+            payment_date,
+            payer: agreement.tenant.clone(),
+        },
+    );
+    agreement.total_rent_paid += amount;
+    agreement.payment_count += 1;
+
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&storage::DataKey::Agreement(id.clone()), &agreement);
+    });
+}
+
+#[test]
+fn test_statement_unsigned_agreement_has_nothing_due() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "STMT_001");
+    client.create_agreement(
+        &agreement_id,
+        &landlord,
+        &tenant,
+        &None,
+        &1000,
+        &2000,
+        &100,
+        &1000000,
+        &0,
+        &Address::generate(&env),
+    );
+
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 0);
+    assert_eq!(statement.total_paid, 0);
+    assert_eq!(statement.outstanding_balance, 0);
+    assert_eq!(statement.next_due_date, None);
+    assert_eq!(statement.deposit_held, 0);
+}
+
+#[test]
+fn test_statement_tracks_due_paid_and_late_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    // Twelve 30-day periods starting at t=100
+    let agreement_id = "STMT_002";
+    client.create_agreement(
+        &String::from_str(&env, agreement_id),
+        &landlord,
+        &tenant,
+        &None,
+        &1000,
+        &2000,
+        &100,
+        &(100 + 12 * agreement::PAYMENT_PERIOD),
+        &0,
+        &Address::generate(&env),
+    );
+    let mut agreement = client
+        .get_agreement(&String::from_str(&env, agreement_id))
+        .unwrap();
+    agreement.status = AgreementStatus::Pending;
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &storage::DataKey::Agreement(String::from_str(&env, agreement_id)),
+            &agreement,
+        );
+    });
+    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+
+    // Period 0 paid on time, period 1 paid two days late
+    record_test_payment(&env, &client, agreement_id, 1000, 100);
+    record_test_payment(
+        &env,
+        &client,
+        agreement_id,
+        1000,
+        100 + agreement::PAYMENT_PERIOD + 172_800,
+    );
+
+    // Now in period 2 (third period due)
+    env.ledger()
+        .with_mut(|li| li.timestamp = 100 + 2 * agreement::PAYMENT_PERIOD + 10);
+
+    let statement = client.get_statement(&String::from_str(&env, agreement_id));
+    assert_eq!(statement.total_due, 3000);
+    assert_eq!(statement.total_paid, 2000);
+    assert_eq!(statement.outstanding_balance, 1000);
+    assert_eq!(
+        statement.next_due_date,
+        Some(100 + 2 * agreement::PAYMENT_PERIOD)
+    );
+    assert_eq!(statement.next_due_amount, 1000);
+    assert_eq!(statement.deposit_held, 2000);
+    assert_eq!(statement.on_time_payments, 1);
+    assert_eq!(statement.late_payments, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_statement_agreement_not_found() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    client.get_statement(&String::from_str(&env, "NONEXISTENT"));
+}
+
+fn create_active_agreement(
+    env: &Env,
+    client: &ContractClient<'_>,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+) {
+    create_pending_agreement(env, client, agreement_id, tenant, landlord);
+    client.sign_agreement(tenant, &String::from_str(env, agreement_id));
+}
+
+#[test]
+fn test_condition_report_submit_and_acknowledge() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "REPORT_001");
+    create_active_agreement(&env, &client, "REPORT_001", &tenant, &landlord);
+
+    let report_hash = String::from_str(&env, "QmMoveInInspection");
+    client.submit_condition_report(&landlord, &agreement_id, &ReportKind::MoveIn, &report_hash);
+
+    let report = client
+        .get_condition_report(&agreement_id, &ReportKind::MoveIn)
+        .unwrap();
+    assert_eq!(report.report_hash, report_hash);
+    assert_eq!(report.submitted_by, landlord);
+    assert!(report.landlord_acknowledged);
+    assert!(!report.tenant_acknowledged);
+    assert_eq!(report.acknowledged_at, None);
+
+    client.acknowledge_condition_report(&tenant, &agreement_id, &ReportKind::MoveIn);
+
+    let report = client
+        .get_condition_report(&agreement_id, &ReportKind::MoveIn)
+        .unwrap();
+    assert!(report.tenant_acknowledged);
+    assert!(report.acknowledged_at.is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_condition_report_rejects_outsider() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    create_active_agreement(&env, &client, "REPORT_002", &tenant, &landlord);
+
+    client.submit_condition_report(
+        &outsider,
+        &String::from_str(&env, "REPORT_002"),
+        &ReportKind::MoveIn,
+        &String::from_str(&env, "QmForged"),
+    );
+}
+
+#[test]
+fn test_condition_report_submitter_cannot_acknowledge() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "REPORT_005");
+    create_active_agreement(&env, &client, "REPORT_005", &tenant, &landlord);
+
+    client.submit_condition_report(
+        &tenant,
+        &agreement_id,
+        &ReportKind::MoveIn,
+        &String::from_str(&env, "QmMoveInInspection"),
+    );
+
+    let result =
+        client.try_acknowledge_condition_report(&tenant, &agreement_id, &ReportKind::MoveIn);
+    assert_eq!(result, Err(Ok(RentalError::Unauthorized)));
+    assert_eq!(env.events().all().len(), 0);
+}
+
+#[test]
+fn test_deposit_release_blocked_by_unacknowledged_move_out_report() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "REPORT_003");
+    create_active_agreement(&env, &client, "REPORT_003", &tenant, &landlord);
+
+    client.submit_condition_report(
+        &landlord,
+        &agreement_id,
+        &ReportKind::MoveOut,
+        &String::from_str(&env, "QmMoveOutInspection"),
+    );
+
+    assert!(!client.can_release_deposit(&agreement_id));
+    let result = client.try_release_deposit(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(RentalError::ReportUnacknowledged)));
+
+    client.acknowledge_condition_report(&tenant, &agreement_id, &ReportKind::MoveOut);
+
+    assert!(client.can_release_deposit(&agreement_id));
+    client.release_deposit(&landlord, &agreement_id);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert!(agreement.deposit_released_at.is_some());
+    assert_eq!(client.get_statement(&agreement_id).deposit_held, 0);
+}
+
+#[test]
+fn test_deposit_release_allowed_after_acknowledgement_timeout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "REPORT_004");
+    create_active_agreement(&env, &client, "REPORT_004", &tenant, &landlord);
+
+    client.submit_condition_report(
+        &landlord,
+        &agreement_id,
+        &ReportKind::MoveOut,
+        &String::from_str(&env, "QmMoveOutInspection"),
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += condition::REPORT_ACK_TIMEOUT);
+
+    client.release_deposit(&landlord, &agreement_id);
+
+    let result = client.try_release_deposit(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(RentalError::DepositAlreadyReleased)));
+}
+
+#[test]
+fn test_create_agreement_rejects_commission_plus_fee_above_100_percent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Config {
+            fee_bps: 500,
+            fee_collector: Address::generate(&env),
+            paused: false,
+        },
+    );
+
+    let result = client.try_create_agreement(
+        &String::from_str(&env, "SPLIT_001"),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Some(Address::generate(&env)),
+        &1000,
+        &2000,
+        &100,
+        &200,
+        &9_600,
+        &Address::generate(&env),
+    );
+    assert_eq!(result, Err(Ok(RentalError::InvalidCommissionRate)));
+}
+
+#[test]
+fn test_agreement_keeps_fee_from_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let agreement_id = String::from_str(&env, "SPLIT_002");
+    client.create_agreement(
+        &agreement_id,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Some(Address::generate(&env)),
+        &1000,
+        &2000,
+        &100,
+        &200,
+        &9_000,
+        &Address::generate(&env),
+    );
+
+    // Raising the fee afterwards would push commission + fee past 100%
+    client.update_config(&Config {
+        fee_bps: 2_000,
+        fee_collector: Address::generate(&env),
+        paused: false,
+    });
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.platform_fee_bps, 100);
+    assert_eq!(agreement.agent_commission_rate, 9_000);
+}
+
+fn create_holdover_agreement(
+    env: &Env,
+    client: &ContractClient<'_>,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+) {
+    let id = String::from_str(env, agreement_id);
+    client.create_agreement(
+        &id,
+        landlord,
+        tenant,
+        &None,
+        &1000,
+        &2000,
+        &100,
+        &(100 + 2 * agreement::PAYMENT_PERIOD),
+        &0,
+        &Address::generate(env),
+    );
+    client.set_holdover_clause(landlord, &id, &11_000, &agreement::PAYMENT_PERIOD);
+    client.submit_agreement(landlord, &id);
+    client.sign_agreement(tenant, &id);
+}
+
+#[test]
+fn test_set_holdover_clause_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let id = String::from_str(&env, "HOLD_EVT");
+
+    client.create_agreement(
+        &id,
+        &landlord,
+        &tenant,
+        &None,
+        &1000,
+        &2000,
+        &100,
+        &(100 + 2 * agreement::PAYMENT_PERIOD),
+        &0,
+        &Address::generate(&env),
+    );
+    client.set_holdover_clause(&landlord, &id, &11_000, &agreement::PAYMENT_PERIOD);
+
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    // Topics: ["holdover_clause", landlord, tenant]
+    let event = events.last().unwrap();
+    assert_eq!(event.1.len(), 3);
+    let name: soroban_sdk::Symbol = event.1.get(0).unwrap().into_val(&env);
+    assert_eq!(name, soroban_sdk::Symbol::new(&env, "holdover_clause"));
+}
+
+#[test]
+fn test_holdover_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "HOLD_001");
+
+    create_holdover_agreement(&env, &client, "HOLD_001", &tenant, &landlord);
+
+    // Cannot start before the term ends
+    let result = client.try_start_holdover(&tenant, &agreement_id);
+    assert_eq!(result, Err(Ok(RentalError::InvalidDate)));
+
+    let end_date = 100 + 2 * agreement::PAYMENT_PERIOD;
+    env.ledger().with_mut(|li| li.timestamp = end_date + 1);
+
+    client.start_holdover(&tenant, &agreement_id);
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Holdover);
+    // Nothing has been paid: the first term period is still owed at the monthly rent
+    assert_eq!(current_rent(&agreement), 1000);
+
+    client.give_holdover_notice(&landlord, &agreement_id);
+    let result = client.try_give_holdover_notice(&tenant, &agreement_id);
+    assert_eq!(result, Err(Ok(RentalError::NoticeAlreadyGiven)));
+
+    let result = client.try_end_holdover(&tenant, &agreement_id);
+    assert_eq!(result, Err(Ok(RentalError::NoticePeriodNotElapsed)));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += agreement::PAYMENT_PERIOD);
+    client.end_holdover(&tenant, &agreement_id);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Completed);
+}
+
+#[test]
+fn test_statement_during_holdover() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "HOLD_002");
+
+    create_holdover_agreement(&env, &client, "HOLD_002", &tenant, &landlord);
+
+    let end_date = 100 + 2 * agreement::PAYMENT_PERIOD;
+    record_test_payment(&env, &client, "HOLD_002", 1000, 100);
+    record_test_payment(
+        &env,
+        &client,
+        "HOLD_002",
+        1000,
+        100 + agreement::PAYMENT_PERIOD,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = end_date + 10);
+    client.start_holdover(&landlord, &agreement_id);
+
+    // First holdover period is due at end_date
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 3100);
+    assert_eq!(statement.total_paid, 2000);
+    assert_eq!(statement.outstanding_balance, 1100);
+    assert_eq!(statement.next_due_date, Some(end_date));
+    assert_eq!(statement.next_due_amount, 1100);
+    assert_eq!(statement.deposit_held, 2000);
+
+    record_test_payment(&env, &client, "HOLD_002", 1100, end_date + 10);
+
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.outstanding_balance, 0);
+    assert_eq!(
+        statement.next_due_date,
+        Some(end_date + agreement::PAYMENT_PERIOD)
+    );
+    // Paid ten seconds after the holdover due date with no grace period
+    assert_eq!(statement.on_time_payments, 2);
+    assert_eq!(statement.late_payments, 1);
+}
+
+#[test]
+fn test_statement_holdover_after_partial_last_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "HOLD_004");
+
+    // A term of one and a half periods: the second term period runs past end_date
+    let end_date = 100 + agreement::PAYMENT_PERIOD + agreement::PAYMENT_PERIOD / 2;
+    client.create_agreement(
+        &agreement_id,
+        &landlord,
+        &tenant,
+        &None,
+        &1000,
+        &2000,
+        &100,
+        &end_date,
+        &0,
+        &Address::generate(&env),
+    );
+    client.set_holdover_clause(
+        &landlord,
+        &agreement_id,
+        &11_000,
+        &agreement::PAYMENT_PERIOD,
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    client.sign_agreement(&tenant, &agreement_id);
+    record_test_payment(&env, &client, "HOLD_004", 1000, 100);
+
+    env.ledger().with_mut(|li| li.timestamp = end_date + 10);
+    client.start_holdover(&tenant, &agreement_id);
+
+    // The last term period is still unpaid and is charged at the monthly rent
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.next_due_amount, current_rent(&agreement));
+    assert_eq!(statement.next_due_amount, 1000);
+    // No holdover period is due until the last term period has run out
+    assert_eq!(statement.total_due, 2000);
+
+    let holdover_start = 100 + 2 * agreement::PAYMENT_PERIOD;
+    env.ledger()
+        .with_mut(|li| li.timestamp = holdover_start + 10);
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 3100);
+}
+
+#[test]
+fn test_release_deposit_during_holdover() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "HOLD_005");
+
+    create_holdover_agreement(&env, &client, "HOLD_005", &tenant, &landlord);
+    env.ledger()
+        .with_mut(|li| li.timestamp = 100 + 2 * agreement::PAYMENT_PERIOD + 1);
+    client.start_holdover(&tenant, &agreement_id);
+
+    client.release_deposit(&landlord, &agreement_id);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Holdover);
+    assert!(agreement.deposit_released_at.is_some());
+}
+
+#[test]
+fn test_start_holdover_without_clause_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    create_active_agreement(&env, &client, "HOLD_003", &tenant, &landlord);
+    env.ledger().with_mut(|li| li.timestamp = 2_000_000);
+
+    let result = client.try_start_holdover(&tenant, &String::from_str(&env, "HOLD_003"));
+    assert_eq!(result, Err(Ok(RentalError::NoHoldoverClause)));
+}
+
+#[test]
+fn test_record_payment_from_payment_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let payment_contract = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "PAY_001");
+
+    initialize_contract_state(&env, &client, &admin);
+    let old_contract = Address::generate(&env);
+    client.set_payment_contract(&old_contract);
+    client.set_payment_contract(&payment_contract);
+
+    // Topics: ["pay_set", admin]; data carries the old and new addresses
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let name: soroban_sdk::Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(name, soroban_sdk::Symbol::new(&env, "pay_set"));
+    let topic_admin: Address = topics.get(1).unwrap().into_val(&env);
+    assert_eq!(topic_admin, admin);
+    let data: soroban_sdk::Map<soroban_sdk::Symbol, Option<Address>> = data.into_val(&env);
+    assert_eq!(
+        data.get(soroban_sdk::Symbol::new(&env, "old_contract")),
+        Some(Some(old_contract))
+    );
+    let new_contract: Address = data
+        .get(soroban_sdk::Symbol::new(&env, "new_contract"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(new_contract, payment_contract);
+    assert_eq!(
+        client.get_payment_contract(),
+        Some(payment_contract.clone())
+    );
+
+    create_active_agreement(&env, &client, "PAY_001", &tenant, &landlord);
+    env.ledger().with_mut(|li| li.timestamp = 150);
+
+    client.record_payment(&agreement_id, &tenant, &900, &0, &100);
+    assert_eq!(
+        env.auths()[0].0,
+        payment_contract,
+        "the payment contract must authorize the record"
+    );
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 1000);
+    assert_eq!(agreement.payment_count, 1);
+    // The single-period term is fully paid, so the next period starts when it runs out
+    assert_eq!(agreement.next_payment_due, 100 + agreement::PAYMENT_PERIOD);
+
+    let split = client.get_payment_split(&agreement_id, &0);
+    assert_eq!(split.landlord_amount, 900);
+    assert_eq!(split.platform_amount, 100);
+    assert_eq!(split.payment_date, 150);
+    assert_eq!(split.payer, tenant);
+
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_paid, 1000);
+    assert_eq!(statement.outstanding_balance, 0);
+}
+
+#[test]
+fn test_record_payment_charges_template_late_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "PAY_LATE");
+
+    initialize_contract_state(&env, &client, &admin);
+    client.set_payment_contract(&Address::generate(&env));
+
+    // 5% late fee after a five-day grace period
+    create_test_template(
+        &env,
+        &client,
+        "TPL_LATE",
+        &landlord,
+        &Address::generate(&env),
+    );
+    client.create_agreement_from_template(
+        &agreement_id,
+        &landlord,
+        &String::from_str(&env, "TPL_LATE"),
+        &tenant,
+        &100,
+        &no_overrides(),
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    client.sign_agreement(&tenant, &agreement_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 100 + 432_001);
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.next_due_amount, 1260);
+
+    let result = client.try_record_payment(&agreement_id, &tenant, &1200, &0, &0);
+    assert_eq!(result, Err(Ok(RentalError::InvalidAmount)));
+    client.record_payment(&agreement_id, &tenant, &1260, &0, &0);
+
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 1260);
+    assert_eq!(statement.total_paid, 1260);
+    assert_eq!(statement.outstanding_balance, 0);
+    assert_eq!(statement.late_payments, 1);
+}
+
+#[test]
+fn test_statement_counts_late_fee_on_overdue_unpaid_periods() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "STMT_LATE");
+
+    // 1200 rent, 5% late fee after a five-day grace period
+    create_test_template(
+        &env,
+        &client,
+        "TPL_LATE",
+        &landlord,
+        &Address::generate(&env),
+    );
+    client.create_agreement_from_template(
+        &agreement_id,
+        &landlord,
+        &String::from_str(&env, "TPL_LATE"),
+        &tenant,
+        &100,
+        &no_overrides(),
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    client.sign_agreement(&tenant, &agreement_id);
+
+    // Period 0 is past its grace period and unpaid: the fee it would be charged counts
+    env.ledger().with_mut(|li| li.timestamp = 100 + 432_001);
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.next_due_amount, 1260);
+    assert_eq!(statement.total_due, 1260);
+    assert_eq!(statement.outstanding_balance, 1260);
+
+    // Period 1 falls due but is still in its grace period
+    env.ledger()
+        .with_mut(|li| li.timestamp = 100 + agreement::PAYMENT_PERIOD + 10);
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 1260 + 1200);
+
+    // Both periods overdue
+    env.ledger()
+        .with_mut(|li| li.timestamp = 100 + agreement::PAYMENT_PERIOD + 432_001);
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 2 * 1260);
+    assert_eq!(statement.outstanding_balance, 2 * 1260);
+    assert_eq!(statement.late_payments, 0);
+}
+
+#[test]
+fn test_record_payment_outside_term_or_holdover() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "HOLD_PAY");
+
+    initialize_contract_state(&env, &client, &admin);
+    client.set_payment_contract(&Address::generate(&env));
+    create_holdover_agreement(&env, &client, "HOLD_PAY", &tenant, &landlord);
+
+    // The term is over but the agreement was never rolled into holdover
+    let end_date = 100 + 2 * agreement::PAYMENT_PERIOD;
+    env.ledger().with_mut(|li| li.timestamp = end_date + 1);
+    let result = client.try_record_payment(&agreement_id, &tenant, &1000, &0, &0);
+    assert_eq!(result, Err(Ok(RentalError::AgreementNotActive)));
+
+    // In holdover, the unpaid term periods are still owed at the monthly rent
+    client.start_holdover(&landlord, &agreement_id);
+    let result = client.try_record_payment(&agreement_id, &tenant, &1100, &0, &0);
+    assert_eq!(result, Err(Ok(RentalError::InvalidAmount)));
+    client.record_payment(&agreement_id, &tenant, &1000, &0, &0);
+    client.record_payment(&agreement_id, &tenant, &1000, &0, &0);
+    client.record_payment(&agreement_id, &tenant, &1100, &0, &0);
+
+    let statement = client.get_statement(&agreement_id);
+    assert_eq!(statement.total_due, 3100);
+    assert_eq!(statement.outstanding_balance, 0);
+
+    // Once the holdover has run out no more rent is taken
+    client.give_holdover_notice(&landlord, &agreement_id);
+    env.ledger()
+        .with_mut(|li| li.timestamp += agreement::PAYMENT_PERIOD);
+    let result = client.try_record_payment(&agreement_id, &tenant, &1100, &0, &0);
+    assert_eq!(result, Err(Ok(RentalError::AgreementNotActive)));
+}
+
+#[test]
+fn test_record_payment_rejects_wrong_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "PAY_002");
+
+    initialize_contract_state(&env, &client, &admin);
+    client.set_payment_contract(&Address::generate(&env));
+    create_active_agreement(&env, &client, "PAY_002", &tenant, &landlord);
+
+    let result = client.try_record_payment(&agreement_id, &tenant, &900, &0, &0);
+    assert_eq!(result, Err(Ok(RentalError::InvalidAmount)));
+}
+
+#[test]
+fn test_record_payment_requires_payment_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    create_active_agreement(&env, &client, "PAY_003", &tenant, &landlord);
+
+    let result =
+        client.try_record_payment(&String::from_str(&env, "PAY_003"), &tenant, &1000, &0, &0);
+    assert_eq!(result, Err(Ok(RentalError::PaymentContractNotSet)));
+}
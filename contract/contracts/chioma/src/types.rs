@@ -10,6 +10,8 @@ pub enum AgreementStatus {
     Cancelled,
     Terminated,
     Disputed,
+    /// Tenant remains after `end_date` under the holdover clause
+    Holdover,
 }
 
 #[contracttype]
@@ -23,7 +25,11 @@ pub struct RentAgreement {
     pub security_deposit: i128,
     pub start_date: u64,
     pub end_date: u64,
+    /// Agent commission in basis points of each rent payment
     pub agent_commission_rate: u32,
+    /// Platform fee in basis points of each rent payment, fixed when the agreement
+    /// is created
+    pub platform_fee_bps: u32,
     pub status: AgreementStatus,
     pub total_rent_paid: i128,
     pub payment_count: u32,
@@ -31,18 +37,105 @@ pub struct RentAgreement {
     pub payment_token: Address,
     pub next_payment_due: u64,
     pub payment_history: Map<u32, PaymentSplit>,
+    pub late_fee_bps: u32,
+    pub late_fee_grace_period: u64,
+    pub terms_hash: Option<String>,
+    pub template_id: Option<String>,
+    pub deposit_released_at: Option<u64>,
+    /// Holdover rent as basis points of `monthly_rent` (e.g. 11,000 = 110%);
+    /// zero when the agreement has no holdover clause
+    pub holdover_rent_bps: u32,
+    /// Seconds of notice either party must give to end a holdover
+    pub holdover_notice_period: u64,
+    pub holdover_started_at: Option<u64>,
+    /// Set once either party gives notice; the holdover ends at this time
+    pub holdover_ends_at: Option<u64>,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentSplit {
     pub landlord_amount: i128,
+    pub agent_amount: i128,
     pub platform_amount: i128,
     pub token: Address,
     pub payment_date: u64,
     pub payer: Address,
 }
 
+/// Which inspection a condition report records.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReportKind {
+    MoveIn,
+    MoveOut,
+}
+
+/// Inspection report attached to an agreement, acknowledged by both parties.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConditionReport {
+    pub agreement_id: String,
+    pub kind: ReportKind,
+    /// Hash of the off-chain inspection report (photos, checklist, etc.)
+    pub report_hash: String,
+    pub submitted_by: Address,
+    pub submitted_at: u64,
+    pub landlord_acknowledged: bool,
+    pub tenant_acknowledged: bool,
+    pub acknowledged_at: Option<u64>,
+}
+
+/// Read-only account summary for an agreement, computed from its payment records.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountStatement {
+    pub agreement_id: String,
+    pub total_due: i128,
+    pub total_paid: i128,
+    pub outstanding_balance: i128,
+    pub next_due_date: Option<u64>,
+    pub next_due_amount: i128,
+    pub deposit_held: i128,
+    pub on_time_payments: u32,
+    pub late_payments: u32,
+    pub as_of: u64,
+}
+
+/// Reusable agreement defaults registered by a landlord.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgreementTemplate {
+    pub template_id: String,
+    pub landlord: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    /// Lease length in seconds, added to the start date to derive `end_date`
+    pub term_length: u64,
+    /// Agent commission in basis points of each rent payment
+    pub agent_commission_rate: u32,
+    pub payment_token: Address,
+    /// Late fee in basis points of the monthly rent
+    pub late_fee_bps: u32,
+    /// Seconds after a due date before a payment counts as late
+    pub late_fee_grace_period: u64,
+    /// Hash of the off-chain standard terms document
+    pub terms_hash: String,
+    pub active: bool,
+    pub created_at: u64,
+}
+
+/// Per-agreement values that replace the template defaults when set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateOverrides {
+    pub agent: Option<Address>,
+    pub monthly_rent: Option<i128>,
+    pub security_deposit: Option<i128>,
+    pub end_date: Option<u64>,
+    pub agent_commission_rate: Option<u32>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Config {
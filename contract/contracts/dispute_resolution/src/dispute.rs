@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String};
 
 use crate::errors::DisputeError;
 use crate::events;
@@ -129,8 +129,21 @@ pub fn raise_dispute(
         return Err(DisputeError::Unauthorized);
     }
 
+    store_new_dispute(env, key, raiser, agreement_id, details_hash);
+
+    Ok(())
+}
+
+fn store_new_dispute(
+    env: &Env,
+    key: DataKey,
+    raised_by: Address,
+    agreement_id: String,
+    details_hash: String,
+) {
     let dispute = Dispute {
         agreement_id: agreement_id.clone(),
+        raised_by,
         details_hash: details_hash.clone(),
         raised_at: env.ledger().timestamp(),
         resolved: false,
@@ -143,6 +156,72 @@ pub fn raise_dispute(
     env.storage().persistent().extend_ttl(&key, 500000, 500000);
 
     events::dispute_raised(env, agreement_id, details_hash);
+}
+
+pub fn register_escrow_contract(
+    env: &Env,
+    admin: Address,
+    escrow_contract: Address,
+) -> Result<(), DisputeError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    let key = DataKey::EscrowContract(escrow_contract.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    events::escrow_contract_registered(env, admin, escrow_contract);
+
+    Ok(())
+}
+
+pub fn is_escrow_contract(env: &Env, escrow_contract: Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowContract(escrow_contract))
+        .unwrap_or(false)
+}
+
+/// Open a panel case on behalf of a registered escrow contract.
+/// The escrow has already checked that the dispute comes from one of its parties,
+/// so the agreement is not looked up in chioma here. Cases are keyed by the escrow
+/// contract and escrow ID, apart from disputes the parties raise on the agreement.
+pub fn raise_escrow_dispute(
+    env: &Env,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+    agreement_id: String,
+    details_hash: String,
+) -> Result<(), DisputeError> {
+    escrow_contract.require_auth();
+
+    if !env.storage().persistent().has(&DataKey::Initialized) {
+        return Err(DisputeError::NotInitialized);
+    }
+
+    if !is_escrow_contract(env, escrow_contract.clone()) {
+        return Err(DisputeError::EscrowContractNotRegistered);
+    }
+
+    if details_hash.is_empty() {
+        return Err(DisputeError::InvalidDetailsHash);
+    }
+
+    let key = DataKey::EscrowDispute(escrow_contract.clone(), escrow_id);
+    if env.storage().persistent().has(&key) {
+        return Err(DisputeError::DisputeAlreadyExists);
+    }
+
+    store_new_dispute(env, key, escrow_contract, agreement_id, details_hash);
 
     Ok(())
 }
@@ -152,6 +231,30 @@ pub fn vote_on_dispute(
     arbiter: Address,
     agreement_id: String,
     favor_landlord: bool,
+) -> Result<(), DisputeError> {
+    let dispute_key = DataKey::Dispute(agreement_id.clone());
+    let vote_key = DataKey::Vote(agreement_id, arbiter.clone());
+    cast_vote(env, arbiter, dispute_key, vote_key, favor_landlord)
+}
+
+pub fn vote_on_escrow_dispute(
+    env: &Env,
+    arbiter: Address,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+    favor_landlord: bool,
+) -> Result<(), DisputeError> {
+    let dispute_key = DataKey::EscrowDispute(escrow_contract.clone(), escrow_id.clone());
+    let vote_key = DataKey::EscrowVote(escrow_contract, escrow_id, arbiter.clone());
+    cast_vote(env, arbiter, dispute_key, vote_key, favor_landlord)
+}
+
+fn cast_vote(
+    env: &Env,
+    arbiter: Address,
+    dispute_key: DataKey,
+    vote_key: DataKey,
+    favor_landlord: bool,
 ) -> Result<(), DisputeError> {
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(DisputeError::NotInitialized);
@@ -170,7 +273,6 @@ pub fn vote_on_dispute(
         return Err(DisputeError::ArbiterNotFound);
     }
 
-    let dispute_key = DataKey::Dispute(agreement_id.clone());
     let mut dispute: Dispute = env
         .storage()
         .persistent()
@@ -181,14 +283,13 @@ pub fn vote_on_dispute(
         return Err(DisputeError::DisputeAlreadyResolved);
     }
 
-    let vote_key = DataKey::Vote(agreement_id.clone(), arbiter.clone());
     if env.storage().persistent().has(&vote_key) {
         return Err(DisputeError::AlreadyVoted);
     }
 
     let vote = Vote {
         arbiter: arbiter.clone(),
-        agreement_id: agreement_id.clone(),
+        agreement_id: dispute.agreement_id.clone(),
         favor_landlord,
         voted_at: env.ledger().timestamp(),
     };
@@ -209,19 +310,30 @@ pub fn vote_on_dispute(
         .persistent()
         .extend_ttl(&dispute_key, 500000, 500000);
 
-    events::vote_cast(env, agreement_id, arbiter, favor_landlord);
+    events::vote_cast(env, dispute.agreement_id, arbiter, favor_landlord);
 
     Ok(())
 }
 
 pub fn resolve_dispute(env: &Env, agreement_id: String) -> Result<DisputeOutcome, DisputeError> {
+    tally_votes(env, DataKey::Dispute(agreement_id))
+}
+
+pub fn resolve_escrow_dispute(
+    env: &Env,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+) -> Result<DisputeOutcome, DisputeError> {
+    tally_votes(env, DataKey::EscrowDispute(escrow_contract, escrow_id))
+}
+
+fn tally_votes(env: &Env, dispute_key: DataKey) -> Result<DisputeOutcome, DisputeError> {
     let state: ContractState = env
         .storage()
         .instance()
         .get(&DataKey::State)
         .ok_or(DisputeError::NotInitialized)?;
 
-    let dispute_key = DataKey::Dispute(agreement_id.clone());
     let mut dispute: Dispute = env
         .storage()
         .persistent()
@@ -254,7 +366,7 @@ pub fn resolve_dispute(env: &Env, agreement_id: String) -> Result<DisputeOutcome
 
     events::dispute_resolved(
         env,
-        agreement_id,
+        dispute.agreement_id,
         outcome.clone(),
         dispute.votes_favor_landlord,
         dispute.votes_favor_tenant,
@@ -268,6 +380,15 @@ pub fn get_dispute(env: &Env, agreement_id: String) -> Option<Dispute> {
     env.storage().persistent().get(&key)
 }
 
+pub fn get_escrow_dispute(
+    env: &Env,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+) -> Option<Dispute> {
+    let key = DataKey::EscrowDispute(escrow_contract, escrow_id);
+    env.storage().persistent().get(&key)
+}
+
 pub fn get_arbiter(env: &Env, arbiter: Address) -> Option<Arbiter> {
     let key = DataKey::Arbiter(arbiter);
     env.storage().persistent().get(&key)
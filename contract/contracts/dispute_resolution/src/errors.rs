@@ -17,4 +17,5 @@ pub enum DisputeError {
     InsufficientVotes = 11,
     AgreementNotFound = 12,
     InvalidAgreementState = 13,
+    EscrowContractNotRegistered = 14,
 }
@@ -17,6 +17,14 @@ pub struct ArbiterAdded {
     pub arbiter: Address,
 }
 
+#[contractevent(topics = ["escrow_registered"])]
+pub struct EscrowContractRegistered {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub escrow_contract: Address,
+}
+
 #[contractevent(topics = ["dispute_raised"])]
 pub struct DisputeRaised {
     #[topic]
@@ -62,6 +70,14 @@ pub(crate) fn dispute_raised(env: &Env, agreement_id: String, details_hash: Stri
     .publish(env);
 }
 
+pub(crate) fn escrow_contract_registered(env: &Env, admin: Address, escrow_contract: Address) {
+    EscrowContractRegistered {
+        admin,
+        escrow_contract,
+    }
+    .publish(env);
+}
+
 pub(crate) fn vote_cast(env: &Env, agreement_id: String, arbiter: Address, favor_landlord: bool) {
     VoteCast {
         agreement_id,
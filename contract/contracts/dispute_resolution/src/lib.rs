@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
 
 mod dispute;
 mod errors;
@@ -12,8 +12,9 @@ mod types;
 mod tests;
 
 pub use dispute::{
-    add_arbiter, get_arbiter, get_arbiter_count, get_dispute, get_vote, raise_dispute,
-    resolve_dispute, vote_on_dispute,
+    add_arbiter, get_arbiter, get_arbiter_count, get_dispute, get_escrow_dispute, get_vote,
+    is_escrow_contract, raise_dispute, raise_escrow_dispute, register_escrow_contract,
+    resolve_dispute, resolve_escrow_dispute, vote_on_dispute, vote_on_escrow_dispute,
 };
 pub use errors::DisputeError;
 pub use storage::DataKey;
@@ -108,6 +109,59 @@ impl DisputeResolutionContract {
         dispute::raise_dispute(&env, raiser, agreement_id, details_hash)
     }
 
+    /// Allow an escrow contract to open panel cases for its deposits (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `escrow_contract` - Address of the escrow contract to trust
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn register_escrow_contract(
+        env: Env,
+        admin: Address,
+        escrow_contract: Address,
+    ) -> Result<(), DisputeError> {
+        dispute::register_escrow_contract(&env, admin, escrow_contract)
+    }
+
+    /// Check whether an escrow contract may open panel cases.
+    ///
+    /// # Arguments
+    /// * `escrow_contract` - Address of the escrow contract
+    ///
+    /// # Returns
+    /// * `bool` - True if the escrow contract is registered
+    pub fn is_escrow_contract(env: Env, escrow_contract: Address) -> bool {
+        dispute::is_escrow_contract(&env, escrow_contract)
+    }
+
+    /// Raise a dispute on behalf of a registered escrow contract.
+    /// The case is keyed by the escrow contract and escrow ID, so it never collides
+    /// with a dispute the parties raise on the agreement itself.
+    ///
+    /// # Arguments
+    /// * `escrow_contract` - The escrow contract delegating its dispute
+    /// * `escrow_id` - The escrow holding the disputed deposit
+    /// * `agreement_id` - The agreement the escrowed deposit belongs to
+    /// * `details_hash` - Hash reference to off-chain evidence/details (IPFS, etc.)
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `EscrowContractNotRegistered` - If the caller is not a registered escrow contract
+    /// * `InvalidDetailsHash` - If the details hash is empty
+    /// * `DisputeAlreadyExists` - If a case is already open for this escrow
+    pub fn raise_escrow_dispute(
+        env: Env,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+        agreement_id: String,
+        details_hash: String,
+    ) -> Result<(), DisputeError> {
+        dispute::raise_escrow_dispute(&env, escrow_contract, escrow_id, agreement_id, details_hash)
+    }
+
     /// Vote on an existing dispute (arbiters only).
     ///
     /// # Arguments
@@ -130,6 +184,26 @@ impl DisputeResolutionContract {
         dispute::vote_on_dispute(&env, arbiter, agreement_id, favor_landlord)
     }
 
+    /// Vote on an escrow's panel case (arbiters only).
+    ///
+    /// # Arguments
+    /// * `arbiter` - The address of the arbiter voting
+    /// * `escrow_contract` - The escrow contract that raised the case
+    /// * `escrow_id` - The escrow holding the disputed deposit
+    /// * `favor_landlord` - True to vote in favor of landlord, false for tenant
+    ///
+    /// # Errors
+    /// Same as `vote_on_dispute`.
+    pub fn vote_on_escrow_dispute(
+        env: Env,
+        arbiter: Address,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+        favor_landlord: bool,
+    ) -> Result<(), DisputeError> {
+        dispute::vote_on_escrow_dispute(&env, arbiter, escrow_contract, escrow_id, favor_landlord)
+    }
+
     /// Resolve a dispute by evaluating votes and determining the outcome.
     ///
     /// # Arguments
@@ -147,6 +221,25 @@ impl DisputeResolutionContract {
         dispute::resolve_dispute(&env, agreement_id)
     }
 
+    /// Resolve an escrow's panel case by evaluating votes.
+    ///
+    /// # Arguments
+    /// * `escrow_contract` - The escrow contract that raised the case
+    /// * `escrow_id` - The escrow holding the disputed deposit
+    ///
+    /// # Returns
+    /// * `DisputeOutcome` - The outcome of the case (FavorLandlord or FavorTenant)
+    ///
+    /// # Errors
+    /// Same as `resolve_dispute`.
+    pub fn resolve_escrow_dispute(
+        env: Env,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+    ) -> Result<DisputeOutcome, DisputeError> {
+        dispute::resolve_escrow_dispute(&env, escrow_contract, escrow_id)
+    }
+
     /// Get information about a specific dispute.
     ///
     /// # Arguments
@@ -158,6 +251,22 @@ impl DisputeResolutionContract {
         dispute::get_dispute(&env, agreement_id)
     }
 
+    /// Get an escrow's panel case.
+    ///
+    /// # Arguments
+    /// * `escrow_contract` - The escrow contract that raised the case
+    /// * `escrow_id` - The escrow holding the disputed deposit
+    ///
+    /// # Returns
+    /// * `Option<Dispute>` - The case if one was raised
+    pub fn get_escrow_dispute(
+        env: Env,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+    ) -> Option<Dispute> {
+        dispute::get_escrow_dispute(&env, escrow_contract, escrow_id)
+    }
+
     /// Get information about a specific arbiter.
     ///
     /// # Arguments
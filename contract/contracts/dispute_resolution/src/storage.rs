@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,4 +9,7 @@ pub enum DataKey {
     ArbiterCount,
     Dispute(String),
     Vote(String, Address),
+    EscrowContract(Address),
+    EscrowDispute(Address, BytesN<32>),
+    EscrowVote(Address, BytesN<32>, Address),
 }
@@ -1,6 +1,6 @@
 use super::*;
 use crate::dispute::RentAgreement;
-use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, BytesN, Env, String};
 
 /// Mock chioma contract that returns a valid RentAgreement for testing.
 #[contract]
@@ -549,3 +549,111 @@ fn test_get_arbiter_count() {
     client.add_arbiter(&admin, &arbiter3);
     assert_eq!(client.get_arbiter_count(), 3);
 }
+
+#[test]
+fn test_escrow_dispute_panel_flow() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let escrow_contract = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &2, &Address::generate(&env));
+    client.register_escrow_contract(&admin, &escrow_contract);
+    assert!(client.is_escrow_contract(&escrow_contract));
+
+    let escrow_id = BytesN::from_array(&env, &[1; 32]);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.raise_escrow_dispute(&escrow_contract, &escrow_id, &agreement_id, &details_hash);
+
+    let result =
+        client.try_raise_escrow_dispute(&escrow_contract, &escrow_id, &agreement_id, &details_hash);
+    assert_eq!(result, Err(Ok(DisputeError::DisputeAlreadyExists)));
+
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
+    client.vote_on_escrow_dispute(&arbiter1, &escrow_contract, &escrow_id, &false);
+    client.vote_on_escrow_dispute(&arbiter2, &escrow_contract, &escrow_id, &false);
+
+    let result = client.try_vote_on_escrow_dispute(&arbiter1, &escrow_contract, &escrow_id, &true);
+    assert_eq!(result, Err(Ok(DisputeError::AlreadyVoted)));
+
+    assert_eq!(
+        client.resolve_escrow_dispute(&escrow_contract, &escrow_id),
+        DisputeOutcome::FavorTenant
+    );
+    let dispute = client
+        .get_escrow_dispute(&escrow_contract, &escrow_id)
+        .unwrap();
+    assert!(dispute.resolved);
+    assert_eq!(dispute.raised_by, escrow_contract);
+    assert_eq!(dispute.agreement_id, agreement_id);
+    assert_eq!(dispute.get_outcome(), Some(DisputeOutcome::FavorTenant));
+
+    // The case lives apart from disputes raised on the agreement itself
+    assert!(client.get_dispute(&agreement_id).is_none());
+}
+
+#[test]
+fn test_escrow_cases_do_not_collide() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let escrow_contract = Address::generate(&env);
+    let other_escrow_contract = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &Address::generate(&env));
+    client.register_escrow_contract(&admin, &escrow_contract);
+    client.register_escrow_contract(&admin, &other_escrow_contract);
+
+    // Same agreement and escrow ID, raised by two escrow contracts
+    let escrow_id = BytesN::from_array(&env, &[1; 32]);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmHash");
+    client.raise_escrow_dispute(&escrow_contract, &escrow_id, &agreement_id, &details_hash);
+    client.raise_escrow_dispute(
+        &other_escrow_contract,
+        &escrow_id,
+        &agreement_id,
+        &details_hash,
+    );
+
+    let arbiter = Address::generate(&env);
+    client.add_arbiter(&admin, &arbiter);
+    client.vote_on_escrow_dispute(&arbiter, &escrow_contract, &escrow_id, &true);
+    client.resolve_escrow_dispute(&escrow_contract, &escrow_id);
+
+    let other = client
+        .get_escrow_dispute(&other_escrow_contract, &escrow_id)
+        .unwrap();
+    assert!(!other.resolved);
+    assert_eq!(other.raised_by, other_escrow_contract);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_unregistered_escrow_cannot_raise_dispute() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &2, &Address::generate(&env));
+
+    client.raise_escrow_dispute(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+        &String::from_str(&env, "agreement_001"),
+        &String::from_str(&env, "QmHash"),
+    );
+}
@@ -28,6 +28,7 @@ pub struct Arbiter {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Dispute {
     pub agreement_id: String,
+    pub raised_by: Address,
     pub details_hash: String,
     pub raised_at: u64,
     pub resolved: bool,
@@ -1,9 +1,9 @@
 //! Access control and role-based authorization for the Escrow contract.
 //! Validates that callers have the proper role to perform actions.
-#[allow(unused_imports)]
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 
 use crate::errors::EscrowError;
+use crate::storage::EscrowStorage;
 use crate::types::Escrow;
 
 /// Access control validation functions.
@@ -37,14 +37,27 @@ impl AccessControl {
         }
     }
 
-    /// Verify caller is any of the three parties (depositor, beneficiary, or arbiter).
+    /// Verify caller is in the escrow's signer set.
     pub fn is_party(escrow: &Escrow, caller: &Address) -> Result<(), EscrowError> {
-        if escrow.depositor == *caller || escrow.beneficiary == *caller || escrow.arbiter == *caller
-        {
-            Ok(())
-        } else {
-            Err(EscrowError::InvalidSigner)
-        }
+        Self::signer_weight(escrow, caller).map(|_| ())
+    }
+
+    /// Weight of the caller's approval, or `InvalidSigner` if it is not a signer.
+    pub fn signer_weight(escrow: &Escrow, caller: &Address) -> Result<u32, EscrowError> {
+        escrow
+            .signers
+            .iter()
+            .find(|signer| signer.address == *caller)
+            .map(|signer| signer.weight)
+            .ok_or(EscrowError::InvalidSigner)
+    }
+
+    /// Total weight of the given approvers; addresses outside the signer set count zero.
+    pub fn approval_weight(escrow: &Escrow, approvers: &Vec<Address>) -> u32 {
+        approvers
+            .iter()
+            .map(|approver| Self::signer_weight(escrow, &approver).unwrap_or(0))
+            .sum()
     }
 
     /// Verify caller is either depositor or beneficiary (the two primary parties).
@@ -56,4 +69,14 @@ impl AccessControl {
             Err(EscrowError::NotAuthorized)
         }
     }
+
+    /// Verify caller is the platform admin set at initialization.
+    pub fn is_admin(env: &Env, caller: &Address) -> Result<(), EscrowError> {
+        let admin = EscrowStorage::get_admin(env).ok_or(EscrowError::NotInitialized)?;
+        if admin == *caller {
+            Ok(())
+        } else {
+            Err(EscrowError::NotAuthorized)
+        }
+    }
 }
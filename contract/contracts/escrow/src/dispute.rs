@@ -1,11 +1,23 @@
 //! Dispute resolution and admin override for the Escrow contract.
-//! Allows either party to freeze funds and requires admin to resolve.
-use soroban_sdk::{token, Address, BytesN, Env, String};
+//! Allows either party to freeze funds and requires admin to resolve, or, for escrows
+//! that delegate their disputes, the dispute_resolution arbiter panel.
+use soroban_sdk::{
+    vec, Address, BytesN, Env, IntoVal, InvokeError, String, Symbol, TryFromVal, Val, Vec,
+};
 
 use crate::access::AccessControl;
 use crate::errors::EscrowError;
+use crate::events;
+use crate::interest::InterestHandler;
+use crate::release::ReleaseHandler;
 use crate::storage::EscrowStorage;
-use crate::types::EscrowStatus;
+use crate::types::{DisputeRecord, Escrow, EscrowStatus, EvidenceEntry, PanelDispute};
+
+/// Seconds both parties have to submit evidence when no window is configured (7 days)
+pub const DEFAULT_RESPONSE_WINDOW: u64 = 604_800;
+
+/// Seconds the arbiter has to resolve when no window is configured (30 days)
+pub const DEFAULT_RESOLUTION_WINDOW: u64 = 2_592_000;
 
 /// Dispute handling and resolution.
 pub struct DisputeHandler;
@@ -19,11 +31,16 @@ impl DisputeHandler {
     /// - Escrow must be in Funded state
     /// - Caller must be depositor or beneficiary
     /// - Dispute reason must not be empty
+    /// - A beneficiary may not dispute after the claim window has closed
     ///
     /// EFFECTS:
     /// - Update escrow status to Disputed
-    /// - Store dispute reason
+    /// - Store dispute reason and open the evidence and resolution windows
     /// - Clear existing approvals (freeze funds)
+    ///
+    /// INTERACTIONS:
+    /// - For delegated escrows, open a case with the dispute_resolution panel keyed by
+    ///   this contract and escrow ID, using the reason as its details hash
     pub fn initiate_dispute(
         env: Env,
         escrow_id: BytesN<32>,
@@ -44,18 +61,61 @@ impl DisputeHandler {
         // Authorize the dispute initiation
         caller.require_auth();
 
+        // The beneficiary must file within the claim window, if one is set
+        ReleaseHandler::check_deduction_deadline(&env, &escrow, &caller)?;
+
         // Verify reason is not empty
         if reason.is_empty() {
             return Err(EscrowError::EmptyDisputeReason);
         }
 
-        // EFFECTS: Update status and store reason
+        // EFFECTS: Update status, store reason and open the evidence window
         escrow.status = EscrowStatus::Disputed;
-        escrow.dispute_reason = Some(reason);
+        escrow.dispute_reason = Some(reason.clone());
         EscrowStorage::save(&env, &escrow);
 
-        // Freeze funds by clearing all approvals
-        EscrowStorage::clear_approvals(&env, &escrow_id);
+        let (response_window, resolution_window) = EscrowStorage::get_dispute_windows(&env)
+            .unwrap_or((DEFAULT_RESPONSE_WINDOW, DEFAULT_RESOLUTION_WINDOW));
+        let now = env.ledger().timestamp();
+        EscrowStorage::set_dispute_record(
+            &env,
+            &escrow_id,
+            &DisputeRecord {
+                initiated_by: caller.clone(),
+                reason: reason.clone(),
+                opened_at: now,
+                response_deadline: now.saturating_add(response_window),
+                resolution_deadline: now.saturating_add(resolution_window),
+            },
+        );
+
+        // Freeze funds by withdrawing all approvals and any pending split
+        EscrowStorage::withdraw_approvals(&env, &escrow);
+
+        // INTERACTIONS: Open a panel case for delegated escrows
+        if let Some(panel) = &escrow.dispute_contract {
+            Self::call_panel::<()>(
+                &env,
+                panel,
+                "raise_escrow_dispute",
+                vec![
+                    &env,
+                    env.current_contract_address().into_val(&env),
+                    escrow_id.clone().into_val(&env),
+                    escrow.agreement_id.clone().into_val(&env),
+                    reason.clone().into_val(&env),
+                ],
+            )?;
+        }
+
+        events::dispute_initiated(
+            &env,
+            escrow_id,
+            escrow.depositor,
+            escrow.beneficiary,
+            caller,
+            reason,
+        );
 
         Ok(())
     }
@@ -65,7 +125,8 @@ impl DisputeHandler {
     ///
     /// CHECKS:
     /// - Escrow must exist
-    /// - Escrow must be in Disputed state
+    /// - Escrow must be in Disputed state and not delegated to the panel
+    /// - Resolution deadline must not have passed
     /// - Caller must be arbiter
     /// - Release target must be beneficiary or depositor
     ///
@@ -75,7 +136,7 @@ impl DisputeHandler {
     /// - Clear approvals
     ///
     /// INTERACTIONS:
-    /// - Token transfer would happen after state update
+    /// - Token transfer after state update
     pub fn resolve_dispute(
         env: Env,
         escrow_id: BytesN<32>,
@@ -83,7 +144,7 @@ impl DisputeHandler {
         release_to: Address,
     ) -> Result<(), EscrowError> {
         // CHECKS: Get and validate escrow
-        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
 
         // Verify caller is arbiter
         AccessControl::is_arbiter(&escrow, &caller)?;
@@ -93,6 +154,13 @@ impl DisputeHandler {
             return Err(EscrowError::InvalidState);
         }
 
+        // Delegated disputes can only be settled from the panel's outcome
+        if escrow.dispute_contract.is_some() {
+            return Err(EscrowError::DisputeDelegated);
+        }
+
+        Self::check_resolution_deadline(&env, &escrow_id)?;
+
         // Authorize the dispute resolution
         caller.require_auth();
 
@@ -101,17 +169,355 @@ impl DisputeHandler {
             return Err(EscrowError::InvalidApprovalTarget);
         }
 
+        Self::settle(&env, escrow, release_to, caller);
+
+        Ok(())
+    }
+
+    /// Settle a delegated dispute from the arbiter panel's outcome. Anyone may call
+    /// this once the panel has resolved the case.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist, be Disputed and delegate to a panel
+    /// - Panel case for this escrow must exist, have been raised by this contract
+    ///   and be resolved (read back via cross-contract call)
+    ///
+    /// EFFECTS:
+    /// - Update escrow status: Released if the panel favours the landlord,
+    ///   Refunded if it favours the tenant
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer after state update
+    pub fn settle_panel_dispute(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        let panel = escrow
+            .dispute_contract
+            .clone()
+            .ok_or(EscrowError::DisputeContractNotSet)?;
+
+        let case: Option<PanelDispute> = Self::call_panel(
+            &env,
+            &panel,
+            "get_escrow_dispute",
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                escrow_id.clone().into_val(&env),
+            ],
+        )?;
+        let case = case.ok_or(EscrowError::PanelNotResolved)?;
+        if case.raised_by != env.current_contract_address() {
+            return Err(EscrowError::PanelCaseMismatch);
+        }
+        if !case.resolved {
+            return Err(EscrowError::PanelNotResolved);
+        }
+
+        // Ties favour the tenant, matching the panel's own outcome rule
+        let release_to = if case.votes_favor_landlord > case.votes_favor_tenant {
+            escrow.beneficiary.clone()
+        } else {
+            escrow.depositor.clone()
+        };
+
+        Self::settle(&env, escrow, release_to, panel);
+
+        Ok(())
+    }
+
+    /// Call the dispute_resolution panel, surfacing any failure as `PanelCallFailed`.
+    fn call_panel<T>(
+        env: &Env,
+        panel: &Address,
+        func: &str,
+        args: Vec<Val>,
+    ) -> Result<T, EscrowError>
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        match env.try_invoke_contract::<T, InvokeError>(panel, &Symbol::new(env, func), args) {
+            Ok(Ok(value)) => Ok(value),
+            _ => Err(EscrowError::PanelCallFailed),
+        }
+    }
+
+    /// Pay the whole escrow to `release_to` and close the dispute.
+    fn settle(env: &Env, mut escrow: Escrow, release_to: Address, resolved_by: Address) {
         // EFFECTS: Update status and clear dispute
-        escrow.status = EscrowStatus::Released;
+        let held = escrow.clone();
+        let is_refund = release_to == escrow.depositor;
+        escrow.status = if is_refund {
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::Released
+        };
         escrow.dispute_reason = None;
-        EscrowStorage::save(&env, &escrow);
+        EscrowStorage::save(env, &escrow);
 
         // Clear approvals
-        EscrowStorage::clear_approvals(&env, &escrow_id);
+        EscrowStorage::clear_approvals(env, &escrow.id);
 
         // INTERACTIONS: Token transfer from escrow contract to release target
-        let token_client = token::Client::new(&env, &escrow.token);
-        token_client.transfer(&env.current_contract_address(), &release_to, &escrow.amount);
+        if is_refund {
+            ReleaseHandler::pay_shares(env, &escrow, escrow.amount, 0);
+        } else {
+            ReleaseHandler::pay_shares(env, &escrow, 0, escrow.amount);
+        }
+        InterestHandler::pay_interest(env, &held);
+
+        let (depositor_amount, beneficiary_amount) = if is_refund {
+            (escrow.amount, 0)
+        } else {
+            (0, escrow.amount)
+        };
+        events::dispute_resolved(
+            env,
+            escrow.id.clone(),
+            escrow.depositor.clone(),
+            escrow.beneficiary.clone(),
+            resolved_by,
+            release_to,
+            escrow.amount,
+            depositor_amount,
+            beneficiary_amount,
+        );
+
+        if is_refund {
+            events::escrow_refunded(
+                env,
+                escrow.id,
+                escrow.depositor,
+                escrow.beneficiary,
+                escrow.amount,
+            );
+        }
+    }
+
+    /// Resolve a dispute by imposing a split between depositor and beneficiary (arbiter only).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist, be Disputed and not delegated to the panel
+    /// - Resolution deadline must not have passed
+    /// - Caller must be arbiter
+    /// - Split must not exceed the escrowed amount
+    ///
+    /// EFFECTS:
+    /// - Update escrow status (Refunded if nothing goes to the beneficiary,
+    ///   Released otherwise) and clear the dispute
+    ///
+    /// INTERACTIONS:
+    /// - Token transfers to both parties after state update
+    /// - Emits `dispute_resolved` with the split alongside the release event
+    pub fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+        deduction_reason: Option<String>,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_arbiter(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if escrow.dispute_contract.is_some() {
+            return Err(EscrowError::DisputeDelegated);
+        }
+
+        Self::check_resolution_deadline(&env, &escrow_id)?;
+
+        caller.require_auth();
+
+        let (depositor_amount, beneficiary_amount) =
+            ReleaseHandler::split_amounts(&escrow, beneficiary_amount)?;
+
+        // EFFECTS + INTERACTIONS
+        let release_to = if beneficiary_amount == 0 {
+            escrow.depositor.clone()
+        } else {
+            escrow.beneficiary.clone()
+        };
+        ReleaseHandler::execute_split(
+            &env,
+            escrow.clone(),
+            depositor_amount,
+            beneficiary_amount,
+            deduction_reason,
+        );
+
+        events::dispute_resolved(
+            &env,
+            escrow_id,
+            escrow.depositor,
+            escrow.beneficiary,
+            caller,
+            release_to,
+            escrow.amount,
+            depositor_amount,
+            beneficiary_amount,
+        );
+
+        Ok(())
+    }
+
+    /// Reject arbiter action once the dispute's resolution deadline has passed.
+    fn check_resolution_deadline(env: &Env, escrow_id: &BytesN<32>) -> Result<(), EscrowError> {
+        match EscrowStorage::get_dispute_record(env, escrow_id) {
+            Some(record) if env.ledger().timestamp() > record.resolution_deadline => {
+                Err(EscrowError::ResolutionDeadlinePassed)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Attach evidence to a disputed escrow (depositor or beneficiary).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Disputed
+    /// - Caller must be depositor or beneficiary
+    /// - Response window must still be open
+    /// - Evidence hash must not be empty
+    ///
+    /// EFFECTS:
+    /// - Append the evidence to the escrow's log
+    pub fn submit_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        evidence_hash: String,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_primary_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        let record =
+            EscrowStorage::get_dispute_record(&env, &escrow_id).ok_or(EscrowError::InvalidState)?;
+        let now = env.ledger().timestamp();
+        if now > record.response_deadline {
+            return Err(EscrowError::ResponseWindowClosed);
+        }
+
+        if evidence_hash.is_empty() {
+            return Err(EscrowError::EmptyEvidence);
+        }
+
+        // EFFECTS: Append to the log
+        EscrowStorage::add_evidence(
+            &env,
+            &escrow_id,
+            EvidenceEntry {
+                submitted_by: caller.clone(),
+                evidence_hash: evidence_hash.clone(),
+                submitted_at: now,
+            },
+        );
+
+        events::evidence_submitted(&env, escrow_id, caller, evidence_hash);
+
+        Ok(())
+    }
+
+    /// Handle a dispute left unresolved past its deadline. Anyone may call.
+    /// If a dispute_resolution contract is configured the arbiter's case escalates to its
+    /// panel, which gets a fresh resolution window; otherwise, or when the panel has not
+    /// ruled in time, it falls back to refunding the depositor.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Disputed
+    /// - Resolution deadline must have passed
+    ///
+    /// EFFECTS:
+    /// - Delegate the escrow to the panel with a new resolution deadline, or settle it
+    ///   as Refunded
+    ///
+    /// INTERACTIONS:
+    /// - Open a panel case, or transfer the deposit back to the depositor
+    pub fn escalate_dispute(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        let mut record =
+            EscrowStorage::get_dispute_record(&env, &escrow_id).ok_or(EscrowError::InvalidState)?;
+        let now = env.ledger().timestamp();
+        if now <= record.resolution_deadline {
+            return Err(EscrowError::ResolutionDeadlineNotReached);
+        }
+
+        // A panel that missed its own deadline gets the default outcome, not another panel
+        let panel = if escrow.dispute_contract.is_some() {
+            None
+        } else {
+            EscrowStorage::get_dispute_contract(&env)
+        };
+
+        match panel {
+            Some(panel) => {
+                // EFFECTS: Hand the case to the panel and give it its own deadline
+                escrow.dispute_contract = Some(panel.clone());
+                EscrowStorage::save(&env, &escrow);
+                let (_, resolution_window) = EscrowStorage::get_dispute_windows(&env)
+                    .unwrap_or((0, DEFAULT_RESOLUTION_WINDOW));
+                record.resolution_deadline = now.saturating_add(resolution_window);
+                EscrowStorage::set_dispute_record(&env, &escrow_id, &record);
+
+                // INTERACTIONS: Open the panel case
+                Self::call_panel::<()>(
+                    &env,
+                    &panel,
+                    "raise_escrow_dispute",
+                    vec![
+                        &env,
+                        env.current_contract_address().into_val(&env),
+                        escrow_id.clone().into_val(&env),
+                        escrow.agreement_id.clone().into_val(&env),
+                        record.reason.clone().into_val(&env),
+                    ],
+                )?;
+
+                events::dispute_escalated(
+                    &env,
+                    escrow_id,
+                    escrow.depositor,
+                    escrow.beneficiary,
+                    true,
+                );
+            }
+            None => {
+                events::dispute_escalated(
+                    &env,
+                    escrow_id,
+                    escrow.depositor.clone(),
+                    escrow.beneficiary.clone(),
+                    false,
+                );
+
+                // Default outcome: the deposit goes back to the depositor
+                let release_to = escrow.depositor.clone();
+                let resolved_by = env.current_contract_address();
+                Self::settle(&env, escrow, release_to, resolved_by);
+            }
+        }
 
         Ok(())
     }
@@ -28,4 +28,68 @@ pub enum EscrowError {
     EmptyDisputeReason = 10,
     /// Invalid approval target (neither beneficiary nor depositor)
     InvalidApprovalTarget = 11,
+    /// Contract has already been initialized
+    AlreadyInitialized = 12,
+    /// Contract has not been initialized
+    NotInitialized = 13,
+    /// Arbiter is not in the platform-approved registry
+    ArbiterNotApproved = 14,
+    /// Split amounts are negative or do not add up to the escrowed amount
+    InvalidSplit = 15,
+    /// No split proposal exists, or it differs from the one being approved
+    ProposalMismatch = 16,
+    /// No claim window has been configured for this escrow
+    NoClaimWindow = 17,
+    /// The claim window has not yet elapsed
+    ClaimWindowOpen = 18,
+    /// The claim window has elapsed; deductions can no longer be filed
+    ClaimWindowClosed = 19,
+    /// Interest rate exceeds 10_000 basis points
+    InvalidInterestRate = 20,
+    /// An escrow already exists for this agreement
+    EscrowAlreadyExists = 21,
+    /// Deposit is non-positive or exceeds what is still owed, or top-up does not raise the amount
+    InvalidFundingAmount = 22,
+    /// Funding schedule is empty, out of order or does not add up to the amount
+    InvalidSchedule = 23,
+    /// Signer set is empty, has duplicates or zero weights, or the threshold is unreachable
+    InvalidPolicy = 24,
+    /// Signer has no approval for this target to revoke
+    ApprovalNotFound = 25,
+    /// No dispute_resolution contract has been configured
+    DisputeContractNotSet = 26,
+    /// Dispute is delegated to the arbiter panel and cannot be resolved directly
+    DisputeDelegated = 27,
+    /// The arbiter panel has not resolved the case yet
+    PanelNotResolved = 28,
+    /// Evidence hash is empty
+    EmptyEvidence = 29,
+    /// The response window for submitting evidence has closed
+    ResponseWindowClosed = 30,
+    /// The arbiter's resolution deadline has passed; the dispute must be escalated
+    ResolutionDeadlinePassed = 31,
+    /// The resolution deadline has not passed yet
+    ResolutionDeadlineNotReached = 32,
+    /// Dispute windows are zero or the resolution window is shorter than the response window
+    InvalidDisputeWindows = 33,
+    /// Funding deadline is not in the future
+    InvalidFundingDeadline = 34,
+    /// No funding deadline is set, or it has not passed yet
+    FundingDeadlineNotReached = 35,
+    /// Platform fee exceeds the allowed maximum
+    InvalidPlatformFee = 36,
+    /// The escrow already holds this token
+    DuplicateAssetLeg = 37,
+    /// The escrow holds no leg in this token
+    AssetLegNotFound = 38,
+    /// No arbiter replacement is pending for this escrow
+    ReplacementNotFound = 39,
+    /// The arbiter replacement's delay has not passed yet
+    ReplacementTimelocked = 40,
+    /// The beneficiary proposed deductions inside the claim window and they are still pending
+    DeductionPending = 41,
+    /// A call to the dispute_resolution panel failed
+    PanelCallFailed = 42,
+    /// The panel's case for this escrow was not raised by this escrow contract
+    PanelCaseMismatch = 43,
 }
@@ -1,13 +1,95 @@
 //! Core escrow lifecycle logic: creation, funding, approvals, and release.
 //! Implements checks-effects-interactions pattern for reentrancy safety.
-use soroban_sdk::{contract, contractimpl, token, xdr::ToXdr, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, vec, xdr::ToXdr, Address, BytesN, Env, String, Vec};
 
 use crate::dispute::DisputeHandler;
 
 use crate::access::AccessControl;
 use crate::errors::EscrowError;
+use crate::events;
+use crate::fees::FeeHandler;
+use crate::funding::FundingHandler;
+use crate::interest::InterestHandler;
+use crate::recovery::RecoveryHandler;
+use crate::release::ReleaseHandler;
 use crate::storage::EscrowStorage;
-use crate::types::{Escrow, EscrowStatus, ReleaseApproval};
+use crate::types::{
+    ApprovalAction, ApprovalRecord, ArbiterReplacement, AssetLeg, DisputeRecord, Escrow,
+    EscrowStatus, EvidenceEntry, FundingInstalment, PartyRole, PlatformFee, ReleaseApproval,
+    ReleaseProposal, Signer,
+};
+
+/// Derive the escrow ID for an agreement between two parties:
+/// `sha256(agreement_id || depositor || beneficiary)`. Binding the parties into the ID
+/// stops anyone else from claiming an agreement's escrow first.
+fn escrow_id_for_agreement(
+    env: &Env,
+    agreement_id: &String,
+    depositor: &Address,
+    beneficiary: &Address,
+) -> BytesN<32> {
+    let mut data = agreement_id.clone().to_xdr(env);
+    data.append(&depositor.clone().to_xdr(env));
+    data.append(&beneficiary.clone().to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// Largest page the listing views return.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Page through a party's index, keeping only escrows whose status is in `statuses`
+/// (all if empty). Filtered pages walk the party's per-status indexes in the order the
+/// statuses are given. `start` counts matching escrows, so pages stay stable under the
+/// filter, and every page reads only the entries it returns.
+fn page_escrows(
+    env: &Env,
+    role: PartyRole,
+    party: &Address,
+    statuses: &Vec<EscrowStatus>,
+    start: u32,
+    limit: u32,
+) -> Vec<BytesN<32>> {
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let mut page = Vec::new(env);
+
+    if statuses.is_empty() {
+        let len = EscrowStorage::party_index_len(env, &role, party);
+        for position in start..len.min(start.saturating_add(limit)) {
+            if let Some(id) = EscrowStorage::party_index_entry(env, &role, party, position) {
+                page.push_back(id);
+            }
+        }
+        return page;
+    }
+
+    let mut skip = start;
+    let mut seen: Vec<EscrowStatus> = Vec::new(env);
+    for status in statuses.iter() {
+        if page.len() >= limit {
+            break;
+        }
+        if seen.contains(&status) {
+            continue;
+        }
+        seen.push_back(status.clone());
+
+        let len = EscrowStorage::status_index_len(env, &role, party, &status);
+        if skip >= len {
+            skip -= len;
+            continue;
+        }
+        let end = len.min(skip + (limit - page.len()));
+        for position in skip..end {
+            if let Some(id) =
+                EscrowStorage::status_index_entry(env, &role, party, &status, position)
+            {
+                page.push_back(id);
+            }
+        }
+        skip = 0;
+    }
+    page
+}
 
 /// Core escrow contract implementation.
 #[contract]
@@ -15,15 +97,134 @@ pub struct EscrowContract;
 
 #[contractimpl]
 impl EscrowContract {
+    /// Initialize the contract with a platform admin and the approved arbiters.
+    ///
+    /// CHECKS:
+    /// - Contract must not already be initialized
+    ///
+    /// EFFECTS:
+    /// - Stores the admin
+    /// - Adds each arbiter to the approved registry
+    pub fn initialize(env: Env, admin: Address, arbiters: Vec<Address>) -> Result<(), EscrowError> {
+        if EscrowStorage::get_admin(&env).is_some() {
+            return Err(EscrowError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        EscrowStorage::set_admin(&env, &admin);
+        for arbiter in arbiters.iter() {
+            EscrowStorage::set_approved_arbiter(&env, &arbiter, true);
+        }
+
+        Ok(())
+    }
+
+    /// Add an arbiter to the approved registry (admin only).
+    pub fn add_arbiter(env: Env, admin: Address, arbiter: Address) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        EscrowStorage::set_approved_arbiter(&env, &arbiter, true);
+        Ok(())
+    }
+
+    /// Remove an arbiter from the approved registry (admin only).
+    /// Existing escrows keep their arbiter; only new escrows are affected.
+    pub fn remove_arbiter(env: Env, admin: Address, arbiter: Address) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        EscrowStorage::set_approved_arbiter(&env, &arbiter, false);
+        Ok(())
+    }
+
+    /// Check if an address is a platform-approved arbiter.
+    pub fn is_approved_arbiter(env: Env, arbiter: Address) -> bool {
+        EscrowStorage::is_approved_arbiter(&env, &arbiter)
+    }
+
+    /// Set the dispute_resolution contract escrows may delegate disputes to (admin only).
+    pub fn set_dispute_contract(
+        env: Env,
+        admin: Address,
+        dispute_contract: Address,
+    ) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        EscrowStorage::set_dispute_contract(&env, &dispute_contract);
+        Ok(())
+    }
+
+    /// Announce a replacement for a lost or unresponsive arbiter on a disputed escrow
+    /// (admin only).
+    /// Takes effect no sooner than `ARBITER_REPLACEMENT_DELAY` after announcement.
+    pub fn propose_arbiter_replacement(
+        env: Env,
+        admin: Address,
+        escrow_id: BytesN<32>,
+        new_arbiter: Address,
+    ) -> Result<(), EscrowError> {
+        RecoveryHandler::propose_arbiter_replacement(env, admin, escrow_id, new_arbiter)
+    }
+
+    /// Withdraw a pending arbiter replacement (admin only).
+    pub fn cancel_arbiter_replacement(
+        env: Env,
+        admin: Address,
+        escrow_id: BytesN<32>,
+    ) -> Result<(), EscrowError> {
+        RecoveryHandler::cancel_arbiter_replacement(env, admin, escrow_id)
+    }
+
+    /// Execute an announced arbiter replacement once its delay has passed.
+    pub fn execute_arbiter_replacement(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        RecoveryHandler::execute_arbiter_replacement(env, escrow_id)
+    }
+
+    /// Get the pending arbiter replacement for an escrow, if any.
+    /// Read-only view function.
+    pub fn get_arbiter_replacement(env: Env, escrow_id: BytesN<32>) -> Option<ArbiterReplacement> {
+        EscrowStorage::get_arbiter_replacement(&env, &escrow_id)
+    }
+
+    /// Set the evidence and resolution windows (seconds) for new disputes (admin only).
+    pub fn set_dispute_windows(
+        env: Env,
+        admin: Address,
+        response_window: u64,
+        resolution_window: u64,
+    ) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        if response_window == 0 || resolution_window < response_window {
+            return Err(EscrowError::InvalidDisputeWindows);
+        }
+
+        EscrowStorage::set_dispute_windows(&env, response_window, resolution_window);
+        Ok(())
+    }
+
     /// Create a new escrow.
     ///
     /// CHECKS:
+    /// - Contract must be initialized
+    /// - Depositor and beneficiary must both authorize
+    /// - No escrow may already exist for the agreement between these parties
     /// - Amount must be positive
     /// - All addresses must be distinct
+    /// - Arbiter must be in the platform-approved registry, so neither party
+    ///   can name a colluding third key
     ///
     /// EFFECTS:
-    /// - Creates new Escrow with Pending status
+    /// - Creates new Escrow with Pending status, keyed by
+    ///   `sha256(agreement_id || depositor || beneficiary)`
+    /// - Wipes the approvals, history, evidence and dispute record of a cancelled
+    ///   escrow previously held under that ID
     /// - Stores escrow in persistent storage
+    /// - Indexes it under its depositor, beneficiary and arbiter
     /// - Increments escrow counter
     ///
     /// INTERACTIONS:
@@ -31,6 +232,7 @@ impl EscrowContract {
     ///   would happen after state update
     pub fn create(
         env: Env,
+        agreement_id: String,
         depositor: Address,
         beneficiary: Address,
         arbiter: Address,
@@ -38,6 +240,13 @@ impl EscrowContract {
         token: Address,
     ) -> Result<BytesN<32>, EscrowError> {
         // CHECKS: Validate inputs
+        if EscrowStorage::get_admin(&env).is_none() {
+            return Err(EscrowError::NotInitialized);
+        }
+
+        depositor.require_auth();
+        beneficiary.require_auth();
+
         if amount <= 0 {
             return Err(EscrowError::InsufficientFunds);
         }
@@ -47,95 +256,266 @@ impl EscrowContract {
             return Err(EscrowError::InvalidSigner);
         }
 
-        // Generate unique escrow ID from hash of parameters
-        let mut data = soroban_sdk::Bytes::new(&env);
-        data.append(&depositor.clone().to_xdr(&env));
-        data.append(&beneficiary.clone().to_xdr(&env));
-        data.append(&arbiter.clone().to_xdr(&env));
-        data.append(&amount.to_xdr(&env));
-        data.append(&token.clone().to_xdr(&env));
-        data.append(&env.ledger().timestamp().to_xdr(&env));
+        // Only a neutral, platform-approved arbiter may hold the third key
+        if !EscrowStorage::is_approved_arbiter(&env, &arbiter) {
+            return Err(EscrowError::ArbiterNotApproved);
+        }
 
-        let escrow_id: BytesN<32> = env.crypto().sha256(&data).into();
+        if agreement_id.is_empty() {
+            return Err(EscrowError::InvalidEscrowId);
+        }
+
+        // One escrow per agreement and pair of parties. A cancelled escrow frees its
+        // agreement for a fresh one.
+        let escrow_id = escrow_id_for_agreement(&env, &agreement_id, &depositor, &beneficiary);
+        let cancelled = EscrowStorage::get(&env, &escrow_id);
+        if let Some(existing) = &cancelled {
+            if existing.status != EscrowStatus::Cancelled {
+                return Err(EscrowError::EscrowAlreadyExists);
+            }
+        }
 
         // EFFECTS: Create and save escrow
         let escrow = Escrow {
             id: escrow_id.clone(),
+            agreement_id,
             depositor: depositor.clone(),
             beneficiary: beneficiary.clone(),
             arbiter: arbiter.clone(),
+            signers: vec![
+                &env,
+                Signer {
+                    address: depositor.clone(),
+                    weight: 1,
+                },
+                Signer {
+                    address: beneficiary.clone(),
+                    weight: 1,
+                },
+                Signer {
+                    address: arbiter.clone(),
+                    weight: 1,
+                },
+            ],
+            threshold: 2,
             amount,
+            funded_amount: 0,
             token,
             status: EscrowStatus::Pending,
             created_at: env.ledger().timestamp(),
             dispute_reason: None,
+            release_date: None,
+            claim_window: 0,
+            funded_at: None,
+            interest_rate_bps: 0,
+            interest_accrued: 0,
+            dispute_contract: None,
+            funding_deadline: None,
+            fee_bps: FeeHandler::current_fee_bps(&env),
+            legs: Vec::new(&env),
         };
 
+        if let Some(previous) = cancelled {
+            EscrowStorage::unindex_escrow(&env, &previous);
+            EscrowStorage::clear_escrow_data(&env, &previous);
+        }
         EscrowStorage::save(&env, &escrow);
+        EscrowStorage::index_escrow(&env, &escrow);
         EscrowStorage::increment_count(&env);
 
+        events::escrow_created(
+            &env,
+            escrow_id.clone(),
+            escrow.depositor,
+            escrow.beneficiary,
+            escrow.arbiter,
+            escrow.amount,
+            escrow.token,
+        );
+
         Ok(escrow_id)
     }
 
-    /// Fund an existing escrow by depositing funds.
-    /// Transitions status from Pending to Funded.
+    /// Replace the default 2-of-3 approval policy with a weighted signer set.
     ///
     /// CHECKS:
-    /// - Escrow must exist
-    /// - Escrow must be in Pending state
-    /// - Caller must be depositor
+    /// - Escrow must exist and be Pending (policy is fixed before funds move)
+    /// - Depositor and beneficiary must both authorize
+    /// - Signers must be non-empty, unique and carry non-zero weights
+    /// - Threshold must be non-zero and reachable by the total weight
     ///
     /// EFFECTS:
-    /// - Update escrow status to Funded
-    ///
-    /// INTERACTIONS:
-    /// - Token transfer would happen after state update (not yet in this version)
-    pub fn fund_escrow(
+    /// - Store the signer set and threshold on the escrow
+    pub fn set_approval_policy(
         env: Env,
         escrow_id: BytesN<32>,
-        caller: Address,
+        signers: Vec<Signer>,
+        threshold: u32,
     ) -> Result<(), EscrowError> {
         // CHECKS: Get and validate escrow
         let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
 
-        // Verify caller is depositor
-        AccessControl::is_depositor(&escrow, &caller)?;
-
-        // Verify escrow is in Pending state
         if escrow.status != EscrowStatus::Pending {
             return Err(EscrowError::InvalidState);
         }
 
-        // Authorize the deposit
-        caller.require_auth();
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
 
-        // EFFECTS: Update status
-        escrow.status = EscrowStatus::Funded;
-        EscrowStorage::save(&env, &escrow);
+        let mut seen: Vec<Address> = Vec::new(&env);
+        let mut total_weight: u32 = 0;
+        for signer in signers.iter() {
+            if signer.weight == 0 || seen.contains(&signer.address) {
+                return Err(EscrowError::InvalidPolicy);
+            }
+            seen.push_back(signer.address.clone());
+            total_weight = total_weight
+                .checked_add(signer.weight)
+                .ok_or(EscrowError::InvalidPolicy)?;
+        }
+        if threshold == 0 || threshold > total_weight {
+            return Err(EscrowError::InvalidPolicy);
+        }
 
-        // INTERACTIONS: Token transfer from depositor to escrow contract
-        let token_client = token::Client::new(&env, &escrow.token);
-        token_client.transfer(&caller, env.current_contract_address(), &escrow.amount);
+        // EFFECTS: Store the policy
+        escrow.signers = signers;
+        escrow.threshold = threshold;
+        EscrowStorage::save(&env, &escrow);
 
         Ok(())
     }
 
+    /// Fund an existing escrow by depositing whatever is still owed, in every token.
+    /// Transitions status from Pending or PartiallyFunded to Funded, unless a
+    /// fee-on-transfer token delivers less than was sent.
+    pub fn fund_escrow(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::fund_all(env, escrow_id, caller)
+    }
+
+    /// Add a further (token, amount) leg to a Pending escrow (depositor and beneficiary).
+    pub fn add_asset_leg(
+        env: Env,
+        escrow_id: BytesN<32>,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::add_asset_leg(env, escrow_id, token, amount)
+    }
+
+    /// Deposit towards one of an escrow's further token legs.
+    pub fn fund_asset_leg(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::deposit_leg(env, escrow_id, caller, token, amount)
+    }
+
+    /// Get the per-token balances of an escrow: the primary token first, then each
+    /// further leg, with the amount owed and the amount deposited so far.
+    /// Read-only view function.
+    pub fn get_escrow_balances(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Vec<AssetLeg>, EscrowError> {
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        let mut balances = vec![
+            &env,
+            AssetLeg {
+                token: escrow.token,
+                amount: escrow.amount,
+                funded_amount: escrow.funded_amount,
+            },
+        ];
+        balances.append(&escrow.legs);
+        Ok(balances)
+    }
+
+    /// Deposit one instalment towards an escrow.
+    /// The escrow stays PartiallyFunded until the full amount has been deposited.
+    pub fn fund_instalment(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::deposit(env, escrow_id, caller, amount)
+    }
+
+    /// Agree a funding schedule (depositor and beneficiary).
+    pub fn set_funding_schedule(
+        env: Env,
+        escrow_id: BytesN<32>,
+        instalments: Vec<FundingInstalment>,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::set_funding_schedule(env, escrow_id, instalments)
+    }
+
+    /// Get the funding schedule of an escrow (empty if none was agreed).
+    /// Read-only view function.
+    pub fn get_funding_schedule(env: Env, escrow_id: BytesN<32>) -> Vec<FundingInstalment> {
+        EscrowStorage::get_funding_schedule(&env, &escrow_id)
+    }
+
+    /// Get the scheduled amount that is past due and not yet deposited.
+    /// Read-only view function.
+    pub fn get_funding_due(env: Env, escrow_id: BytesN<32>) -> Result<i128, EscrowError> {
+        FundingHandler::funding_due(&env, &escrow_id)
+    }
+
+    /// Raise the escrowed amount after an agreement amendment (depositor and beneficiary).
+    pub fn increase_amount(
+        env: Env,
+        escrow_id: BytesN<32>,
+        new_amount: i128,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::increase_amount(env, escrow_id, new_amount)
+    }
+
+    /// Agree a deadline by which the escrow must be fully funded (depositor and beneficiary).
+    pub fn set_funding_deadline(
+        env: Env,
+        escrow_id: BytesN<32>,
+        deadline: u64,
+    ) -> Result<(), EscrowError> {
+        FundingHandler::set_funding_deadline(env, escrow_id, deadline)
+    }
+
+    /// Cancel a Pending escrow (depositor or beneficiary).
+    pub fn cancel(env: Env, escrow_id: BytesN<32>, caller: Address) -> Result<(), EscrowError> {
+        FundingHandler::cancel(env, escrow_id, caller)
+    }
+
+    /// Expire an escrow past its funding deadline, refunding any partial deposit.
+    pub fn expire(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        FundingHandler::expire(env, escrow_id)
+    }
+
     /// Approve release of funds to a target address.
-    /// Implements 2-of-3 multi-sig: executes transfer when ≥2 unique signers approve same target.
+    /// Implements weighted M-of-N multi-sig: executes transfer once the signers approving
+    /// the same target reach the escrow's threshold (2-of-3 by default).
     ///
     /// CHECKS:
-    /// - Escrow must exist and be Funded (or Disputed if arbiter)
+    /// - Escrow must exist and be Funded; disputed escrows are settled through the
+    ///   dispute functions instead
     /// - Caller must be a valid party
     /// - Release target must be beneficiary or depositor
     /// - Caller must not have already approved this same target
     ///
     /// EFFECTS:
-    /// - Add approval to storage
-    /// - Count approvals; if ≥2 unique parties approve same target, update escrow status
-    /// - Clear approvals after execution
+    /// - Add approval to storage and to the append-only approval history
+    /// - Sum approval weights; once the threshold is met for a target, update escrow status
+    ///   (Refunded when the target is the depositor, Released otherwise)
+    /// - Clear active approvals after execution (the history is kept)
     ///
     /// INTERACTIONS:
-    /// - Token transfer after all state updates
+    /// - Token transfer after all state updates, plus accrued interest to the depositor
     pub fn approve_release(
         env: Env,
         escrow_id: BytesN<32>,
@@ -166,49 +546,286 @@ impl EscrowContract {
             return Err(EscrowError::AlreadySigned);
         }
 
-        // EFFECTS: Record the approval flag and increment the counter
+        // EFFECTS: Record the approval flag and add the signer's weight
+        let weight = AccessControl::signer_weight(&escrow, &caller)?;
         EscrowStorage::set_signer_approved(&env, &escrow_id, &caller, &release_to);
-        EscrowStorage::increment_approval_count(&env, &escrow_id, &release_to);
+        EscrowStorage::add_approval_weight(&env, &escrow_id, &release_to, weight);
 
-        // Also persist the approval record for audit trail
+        // Also persist the approval record and the append-only audit trail
         let new_approval = ReleaseApproval {
             signer: caller.clone(),
             release_to: release_to.clone(),
             timestamp: env.ledger().timestamp(),
         };
         EscrowStorage::add_approval(&env, &escrow_id, new_approval);
+        EscrowStorage::append_approval_history(
+            &env,
+            &escrow_id,
+            ApprovalRecord {
+                signer: caller.clone(),
+                release_to: release_to.clone(),
+                action: ApprovalAction::Approved,
+                beneficiary_amount: None,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
-        // Read the updated count via O(1) lookup
+        // Read the updated weight via O(1) lookup
         let approval_count =
-            EscrowStorage::get_approval_count_for_target(&env, &escrow_id, &release_to);
+            EscrowStorage::get_approval_weight_for_target(&env, &escrow_id, &release_to);
+
+        events::release_approved(
+            &env,
+            escrow_id.clone(),
+            caller.clone(),
+            release_to.clone(),
+            approval_count,
+        );
 
-        // If 2 or more unique signers approve, execute release
-        if approval_count >= 2 {
+        // Execute once the approving signers reach the escrow's threshold
+        if approval_count >= escrow.threshold {
             let mut escrow_to_update =
                 EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
 
             // Determine final status based on release target
-            escrow_to_update.status = EscrowStatus::Released;
+            let is_refund = release_to == escrow.depositor;
+            escrow_to_update.status = if is_refund {
+                EscrowStatus::Refunded
+            } else {
+                EscrowStatus::Released
+            };
             EscrowStorage::save(&env, &escrow_to_update);
 
-            // Clear approvals and counters after execution
+            // Clear approvals, counters and any pending split after execution
             EscrowStorage::clear_approvals(&env, &escrow_id);
-            let targets = [escrow.beneficiary.clone(), escrow.depositor.clone()];
-            let signers = [
-                escrow.depositor.clone(),
-                escrow.beneficiary.clone(),
-                escrow.arbiter.clone(),
-            ];
-            EscrowStorage::clear_approval_counts(&env, &escrow_id, &targets, &signers);
+            EscrowStorage::clear_proposal(&env, &escrow_id);
+            EscrowStorage::clear_approval_counts(&env, &escrow);
 
             // INTERACTIONS: Token transfer from escrow contract to release target
-            let token_client = token::Client::new(&env, &escrow.token);
-            token_client.transfer(&env.current_contract_address(), &release_to, &escrow.amount);
+            if is_refund {
+                ReleaseHandler::pay_shares(&env, &escrow, escrow.amount, 0);
+            } else {
+                ReleaseHandler::pay_shares(&env, &escrow, 0, escrow.amount);
+            }
+            InterestHandler::pay_interest(&env, &escrow);
+
+            if is_refund {
+                events::escrow_refunded(
+                    &env,
+                    escrow_id,
+                    escrow.depositor,
+                    escrow.beneficiary,
+                    escrow.amount,
+                );
+            } else {
+                events::escrow_released(
+                    &env,
+                    escrow_id,
+                    escrow.depositor,
+                    escrow.beneficiary,
+                    release_to,
+                    escrow.amount,
+                );
+            }
         }
 
         Ok(())
     }
 
+    /// Withdraw an earlier release approval before it takes effect.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded
+    /// - Caller must be a valid party
+    /// - Caller must have an active approval for this target
+    ///
+    /// EFFECTS:
+    /// - Remove the approval flag, weight and record
+    /// - Append a revocation to the approval history
+    pub fn revoke_approval(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        release_to: Address,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        if !EscrowStorage::has_signer_approved(&env, &escrow_id, &caller, &release_to) {
+            return Err(EscrowError::ApprovalNotFound);
+        }
+
+        // EFFECTS: Withdraw the approval and record the revocation
+        let weight = AccessControl::signer_weight(&escrow, &caller)?;
+        EscrowStorage::remove_signer_approved(&env, &escrow_id, &caller, &release_to);
+        EscrowStorage::subtract_approval_weight(&env, &escrow_id, &release_to, weight);
+        EscrowStorage::remove_approval(&env, &escrow_id, &caller, &release_to);
+        EscrowStorage::append_approval_history(
+            &env,
+            &escrow_id,
+            ApprovalRecord {
+                signer: caller.clone(),
+                release_to: release_to.clone(),
+                action: ApprovalAction::Revoked,
+                beneficiary_amount: None,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        let approval_count =
+            EscrowStorage::get_approval_weight_for_target(&env, &escrow_id, &release_to);
+        events::approval_revoked(&env, escrow_id, caller, release_to, approval_count);
+
+        Ok(())
+    }
+
+    /// Get the approvals and revocations recorded for an escrow, oldest first.
+    /// `start` and `limit` page through the records, with `limit` capped at
+    /// `MAX_PAGE_SIZE`. Remains available after the escrow is released or refunded.
+    /// Read-only view function.
+    pub fn get_approval_history(
+        env: Env,
+        escrow_id: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<ApprovalRecord>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_approval_history(
+            &env,
+            &escrow_id,
+            start,
+            limit.min(MAX_PAGE_SIZE),
+        ))
+    }
+
+    /// Get the number of records in an escrow's approval history.
+    /// Read-only view function.
+    pub fn get_approval_history_len(env: Env, escrow_id: BytesN<32>) -> u32 {
+        EscrowStorage::approval_history_len(&env, &escrow_id)
+    }
+
+    /// Approve refunding the full escrow to the depositor.
+    /// Shorthand for `approve_release` targeting the depositor; once the weight of the
+    /// approvals for the depositor reaches the escrow's threshold, the refund runs and
+    /// the escrow is marked Refunded.
+    pub fn approve_refund(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Self::approve_release(env, escrow_id, caller, escrow.depositor)
+    }
+
+    /// Propose dividing the escrow: `beneficiary_amount` goes to the beneficiary and
+    /// the remainder returns to the depositor. The proposer's approval is recorded.
+    pub fn propose_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+        deduction_reason: Option<String>,
+    ) -> Result<(), EscrowError> {
+        ReleaseHandler::propose_split(env, escrow_id, caller, beneficiary_amount, deduction_reason)
+    }
+
+    /// Approve the pending split; executes once the approvals for the exact split reach
+    /// the escrow's threshold.
+    pub fn approve_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+    ) -> Result<(), EscrowError> {
+        ReleaseHandler::approve_split(env, escrow_id, caller, beneficiary_amount)
+    }
+
+    /// Withdraw an approval of the pending split before it executes.
+    pub fn revoke_split_approval(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        ReleaseHandler::revoke_split_approval(env, escrow_id, caller)
+    }
+
+    /// Set the release date and claim window, agreed by depositor and beneficiary.
+    pub fn set_claim_window(
+        env: Env,
+        escrow_id: BytesN<32>,
+        release_date: u64,
+        claim_window: u64,
+    ) -> Result<(), EscrowError> {
+        ReleaseHandler::set_claim_window(env, escrow_id, release_date, claim_window)
+    }
+
+    /// Reclaim the full deposit once the claim window has passed without a dispute.
+    pub fn claim_refund(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        ReleaseHandler::claim_refund(env, escrow_id, caller)
+    }
+
+    /// Set the annual interest rate (bps) for escrows funded from now on (admin only).
+    pub fn set_interest_rate(env: Env, admin: Address, rate_bps: u32) -> Result<(), EscrowError> {
+        InterestHandler::set_interest_rate(env, admin, rate_bps)
+    }
+
+    /// Set the platform fee for escrows created from now on, and its treasury (admin only).
+    pub fn set_platform_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), EscrowError> {
+        FeeHandler::set_platform_fee(env, admin, fee_bps, treasury)
+    }
+
+    /// Get the platform fee configuration, if one is set.
+    /// Read-only view function.
+    pub fn get_platform_fee(env: Env) -> Option<PlatformFee> {
+        EscrowStorage::get_platform_fee(&env)
+    }
+
+    /// Top up the reserve that pays deposit interest for a token.
+    pub fn fund_interest_reserve(
+        env: Env,
+        funder: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        InterestHandler::fund_interest_reserve(env, funder, token, amount)
+    }
+
+    /// Get the interest reserve balance for a token.
+    /// Read-only view function.
+    pub fn get_interest_reserve(env: Env, token: Address) -> i128 {
+        EscrowStorage::get_interest_reserve(&env, &token)
+    }
+
+    /// Get the interest accrued so far on an escrow.
+    /// Read-only view function.
+    pub fn get_accrued_interest(env: Env, escrow_id: BytesN<32>) -> Result<i128, EscrowError> {
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(InterestHandler::accrued_interest(&env, &escrow))
+    }
+
+    /// Get the pending split proposal of an escrow.
+    /// Read-only view function.
+    pub fn get_split_proposal(env: Env, escrow_id: BytesN<32>) -> Option<ReleaseProposal> {
+        EscrowStorage::get_proposal(&env, &escrow_id)
+    }
+
     /// Set up a dispute on an escrow.
     pub fn initiate_dispute(
         env: Env,
@@ -219,7 +836,80 @@ impl EscrowContract {
         DisputeHandler::initiate_dispute(env, escrow_id, caller, reason)
     }
 
+    /// Hand this escrow's disputes to the dispute_resolution arbiter panel.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending
+    /// - Depositor and beneficiary must both authorize
+    /// - A dispute contract must be configured
+    ///
+    /// EFFECTS:
+    /// - Record the panel contract on the escrow; from then on only the panel's
+    ///   outcome can settle a dispute
+    pub fn delegate_disputes(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending {
+            return Err(EscrowError::InvalidState);
+        }
+
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        let panel =
+            EscrowStorage::get_dispute_contract(&env).ok_or(EscrowError::DisputeContractNotSet)?;
+
+        // EFFECTS: Delegate
+        escrow.dispute_contract = Some(panel);
+        EscrowStorage::save(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Attach an evidence hash to a disputed escrow during the response window.
+    pub fn submit_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        evidence_hash: String,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::submit_evidence(env, escrow_id, caller, evidence_hash)
+    }
+
+    /// Escalate a dispute left unresolved past its deadline: an arbiter case goes to the
+    /// panel if one is configured, and a stalled panel case falls back to a refund.
+    pub fn escalate_dispute(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        DisputeHandler::escalate_dispute(env, escrow_id)
+    }
+
+    /// Get the full evidence log of an escrow, oldest first.
+    /// Read-only view function.
+    pub fn get_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Vec<EvidenceEntry>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_evidence(&env, &escrow_id))
+    }
+
+    /// Get the dispute timeline (opener, reason and deadlines) of an escrow.
+    /// Read-only view function.
+    pub fn get_dispute_record(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Option<DisputeRecord>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_dispute_record(&env, &escrow_id))
+    }
+
+    /// Settle a delegated dispute once the arbiter panel has resolved it.
+    pub fn settle_panel_dispute(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        DisputeHandler::settle_panel_dispute(env, escrow_id)
+    }
+
     /// Resolve a dispute by releasing funds to a target.
+    /// Resolving in favour of the depositor marks the escrow Refunded.
     pub fn resolve_dispute(
         env: Env,
         escrow_id: BytesN<32>,
@@ -229,6 +919,36 @@ impl EscrowContract {
         DisputeHandler::resolve_dispute(env, escrow_id, caller, release_to)
     }
 
+    /// Resolve a dispute by imposing a split between depositor and beneficiary.
+    pub fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+        deduction_reason: Option<String>,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::resolve_dispute_split(
+            env,
+            escrow_id,
+            caller,
+            beneficiary_amount,
+            deduction_reason,
+        )
+    }
+
+    /// Get the escrow holding the deposit for an agreement between a depositor and a
+    /// beneficiary.
+    /// Read-only view function.
+    pub fn get_escrow_by_agreement(
+        env: Env,
+        agreement_id: String,
+        depositor: Address,
+        beneficiary: Address,
+    ) -> Result<Escrow, EscrowError> {
+        let escrow_id = escrow_id_for_agreement(&env, &agreement_id, &depositor, &beneficiary);
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)
+    }
+
     /// Get details of an escrow.
     /// Read-only view function.
     pub fn get_escrow(env: Env, escrow_id: BytesN<32>) -> Result<Escrow, EscrowError> {
@@ -236,7 +956,8 @@ impl EscrowContract {
     }
 
     /// Get approval count for a specific release target.
-    /// Returns number of unique signers approving release to a specific address.
+    /// Returns the total weight of signers approving release to a specific address,
+    /// which is the number of signers when all weights are 1.
     /// Uses O(1) dedicated counter storage instead of iterating the approvals list.
     pub fn get_approval_count(
         env: Env,
@@ -245,10 +966,75 @@ impl EscrowContract {
     ) -> Result<u32, EscrowError> {
         // Verify escrow exists
         EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
-        Ok(EscrowStorage::get_approval_count_for_target(
+        Ok(EscrowStorage::get_approval_weight_for_target(
             &env,
             &escrow_id,
             &release_to,
         ))
     }
+
+    /// Get the number of live escrows (created and not cancelled or expired).
+    /// Read-only view function.
+    pub fn get_escrow_count(env: Env) -> u32 {
+        EscrowStorage::get_count(&env)
+    }
+
+    /// List escrow IDs the address deposits into. Unfiltered, they come in creation
+    /// order, except that an escrow leaving the index (when its agreement gets a fresh
+    /// escrow) has its place taken by the newest entry.
+    /// `statuses` filters the result (empty returns every status); filtered results are
+    /// grouped by status in the order given, each group in the order its escrows reached
+    /// that status, subject to the same reordering on removal. `start` and `limit` page
+    /// through the matches, with `limit` capped at `MAX_PAGE_SIZE`.
+    /// Read-only view function.
+    pub fn get_escrows_by_depositor(
+        env: Env,
+        depositor: Address,
+        statuses: Vec<EscrowStatus>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        page_escrows(
+            &env,
+            PartyRole::Depositor,
+            &depositor,
+            &statuses,
+            start,
+            limit,
+        )
+    }
+
+    /// List escrow IDs held for the address as beneficiary.
+    /// Ordering, filtering and paging work as in `get_escrows_by_depositor`.
+    /// Read-only view function.
+    pub fn get_escrows_by_beneficiary(
+        env: Env,
+        beneficiary: Address,
+        statuses: Vec<EscrowStatus>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        page_escrows(
+            &env,
+            PartyRole::Beneficiary,
+            &beneficiary,
+            &statuses,
+            start,
+            limit,
+        )
+    }
+
+    /// List escrow IDs the address arbitrates. Filtering on `Disputed` gives the
+    /// arbiter's work queue.
+    /// Ordering, filtering and paging work as in `get_escrows_by_depositor`.
+    /// Read-only view function.
+    pub fn get_escrows_by_arbiter(
+        env: Env,
+        arbiter: Address,
+        statuses: Vec<EscrowStatus>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        page_escrows(&env, PartyRole::Arbiter, &arbiter, &statuses, start, limit)
+    }
 }
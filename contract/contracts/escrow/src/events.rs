@@ -0,0 +1,677 @@
+//! Lifecycle events for the Escrow contract.
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
+
+/// Event emitted when an escrow is created
+/// Topics: ["esc_created", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_created"])]
+pub struct EscrowCreated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub arbiter: Address,
+    pub amount: i128,
+    pub token: Address,
+}
+
+/// Event emitted when the depositor funds an escrow
+/// Topics: ["esc_funded", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_funded"])]
+pub struct EscrowFunded {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub amount: i128,
+}
+
+/// Event emitted when the depositor pays an instalment short of the full amount
+/// Topics: ["esc_instalment", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_instalment"])]
+pub struct InstalmentFunded {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub funded_amount: i128,
+}
+
+/// Event emitted when the depositor pays into one of an escrow's additional token legs
+/// Topics: ["esc_leg_funded", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_leg_funded"])]
+pub struct LegFunded {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub funded_amount: i128,
+}
+
+/// Event emitted when an amended agreement raises the escrowed amount
+/// Topics: ["esc_amount_up", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_amount_up"])]
+pub struct AmountIncreased {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub old_amount: i128,
+    pub new_amount: i128,
+}
+
+/// Event emitted when an escrow is cancelled or expires before it is fully funded
+/// Topics: ["esc_cancelled", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_cancelled"])]
+pub struct EscrowCancelled {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub cancelled_by: Address,
+    pub refunded: i128,
+}
+
+/// Event emitted when the platform fee is taken from a release
+/// Topics: ["esc_fee", escrow_id: BytesN<32>, treasury: Address]
+#[contractevent(topics = ["esc_fee"])]
+pub struct FeeCollected {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub treasury: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Event emitted when the admin announces a replacement for an escrow's arbiter
+/// Topics: ["esc_arb_prop", escrow_id: BytesN<32>, old_arbiter: Address]
+#[contractevent(topics = ["esc_arb_prop"])]
+pub struct ArbiterReplacementProposed {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub old_arbiter: Address,
+    pub new_arbiter: Address,
+    pub executable_at: u64,
+}
+
+/// Event emitted when the admin withdraws a pending arbiter replacement
+/// Topics: ["esc_arb_cancel", escrow_id: BytesN<32>]
+#[contractevent(topics = ["esc_arb_cancel"])]
+pub struct ArbiterReplacementCancelled {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    pub new_arbiter: Address,
+}
+
+/// Event emitted when an announced arbiter replacement takes effect
+/// Topics: ["esc_arb_replaced", escrow_id: BytesN<32>, old_arbiter: Address]
+#[contractevent(topics = ["esc_arb_replaced"])]
+pub struct ArbiterReplaced {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub old_arbiter: Address,
+    pub new_arbiter: Address,
+}
+
+/// Event emitted when a party approves a release
+/// Topics: ["esc_approved", escrow_id: BytesN<32>, signer: Address]
+#[contractevent(topics = ["esc_approved"])]
+pub struct ReleaseApproved {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub signer: Address,
+    pub release_to: Address,
+    pub approval_count: u32,
+}
+
+/// Event emitted when a party withdraws a release approval
+/// Topics: ["esc_revoked", escrow_id: BytesN<32>, signer: Address]
+#[contractevent(topics = ["esc_revoked"])]
+pub struct ApprovalRevoked {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub signer: Address,
+    pub release_to: Address,
+    pub approval_count: u32,
+}
+
+/// Event emitted when escrowed funds are released
+/// Topics: ["esc_released", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_released"])]
+pub struct EscrowReleased {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub release_to: Address,
+    pub amount: i128,
+}
+
+/// Event emitted when escrowed funds are returned to the depositor
+/// Topics: ["esc_refunded", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_refunded"])]
+pub struct EscrowRefunded {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub amount: i128,
+}
+
+/// Event emitted when a party disputes an escrow
+/// Topics: ["esc_disputed", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_disputed"])]
+pub struct DisputeInitiated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub initiated_by: Address,
+    pub reason: String,
+}
+
+/// Event emitted when a party attaches evidence to a dispute
+/// Topics: ["esc_evidence", escrow_id: BytesN<32>, submitted_by: Address]
+#[contractevent(topics = ["esc_evidence"])]
+pub struct EvidenceSubmitted {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub submitted_by: Address,
+    pub evidence_hash: String,
+}
+
+/// Event emitted when a dispute passes its resolution deadline unresolved
+/// Topics: ["esc_escalated", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_escalated"])]
+pub struct DisputeEscalated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub to_panel: bool,
+}
+
+/// Event emitted when the arbiter resolves a dispute, whole or as a split
+/// Topics: ["esc_resolved", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_resolved"])]
+pub struct DisputeResolved {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub arbiter: Address,
+    pub release_to: Address,
+    pub amount: i128,
+    pub depositor_amount: i128,
+    pub beneficiary_amount: i128,
+}
+
+/// Event emitted when a party proposes splitting the escrow
+/// Topics: ["esc_split_prop", escrow_id: BytesN<32>, proposed_by: Address]
+#[contractevent(topics = ["esc_split_prop"])]
+pub struct SplitProposed {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub proposed_by: Address,
+    pub depositor_amount: i128,
+    pub beneficiary_amount: i128,
+    pub deduction_reason: Option<String>,
+}
+
+/// Event emitted when a party's approval of the pending split is withdrawn, either by
+/// the party or because a new proposal replaced the split
+/// Topics: ["esc_split_rev", escrow_id: BytesN<32>, signer: Address]
+#[contractevent(topics = ["esc_split_rev"])]
+pub struct SplitApprovalRevoked {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub signer: Address,
+    pub beneficiary_amount: i128,
+}
+
+/// Event emitted when escrowed funds are divided between depositor and beneficiary
+/// Topics: ["esc_split", escrow_id: BytesN<32>, depositor: Address, beneficiary: Address]
+#[contractevent(topics = ["esc_split"])]
+pub struct SplitReleased {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub depositor_amount: i128,
+    pub beneficiary_amount: i128,
+    pub deduction_reason: Option<String>,
+}
+
+/// Event emitted when accrued interest is paid to the depositor
+/// Topics: ["esc_interest", escrow_id: BytesN<32>, depositor: Address]
+#[contractevent(topics = ["esc_interest"])]
+pub struct InterestPaid {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub depositor: Address,
+    pub amount: i128,
+}
+
+/// Helper function to emit escrow created event
+pub(crate) fn escrow_created(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    arbiter: Address,
+    amount: i128,
+    token: Address,
+) {
+    EscrowCreated {
+        escrow_id,
+        depositor,
+        beneficiary,
+        arbiter,
+        amount,
+        token,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow funded event
+pub(crate) fn escrow_funded(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    amount: i128,
+) {
+    EscrowFunded {
+        escrow_id,
+        depositor,
+        beneficiary,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit instalment funded event
+pub(crate) fn instalment_funded(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    amount: i128,
+    funded_amount: i128,
+) {
+    InstalmentFunded {
+        escrow_id,
+        depositor,
+        beneficiary,
+        amount,
+        funded_amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit amount increased event
+pub(crate) fn amount_increased(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    old_amount: i128,
+    new_amount: i128,
+) {
+    AmountIncreased {
+        escrow_id,
+        depositor,
+        beneficiary,
+        old_amount,
+        new_amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit release approved event
+pub(crate) fn release_approved(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    signer: Address,
+    release_to: Address,
+    approval_count: u32,
+) {
+    ReleaseApproved {
+        escrow_id,
+        signer,
+        release_to,
+        approval_count,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit approval revoked event
+pub(crate) fn approval_revoked(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    signer: Address,
+    release_to: Address,
+    approval_count: u32,
+) {
+    ApprovalRevoked {
+        escrow_id,
+        signer,
+        release_to,
+        approval_count,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow released event
+pub(crate) fn escrow_released(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    release_to: Address,
+    amount: i128,
+) {
+    EscrowReleased {
+        escrow_id,
+        depositor,
+        beneficiary,
+        release_to,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit fee collected event
+pub(crate) fn fee_collected(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    treasury: Address,
+    token: Address,
+    amount: i128,
+) {
+    FeeCollected {
+        escrow_id,
+        treasury,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit leg funded event
+pub(crate) fn leg_funded(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    token: Address,
+    amount: i128,
+    funded_amount: i128,
+) {
+    LegFunded {
+        escrow_id,
+        depositor,
+        beneficiary,
+        token,
+        amount,
+        funded_amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit arbiter replacement proposed event
+pub(crate) fn arbiter_replacement_proposed(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    old_arbiter: Address,
+    new_arbiter: Address,
+    executable_at: u64,
+) {
+    ArbiterReplacementProposed {
+        escrow_id,
+        old_arbiter,
+        new_arbiter,
+        executable_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit arbiter replacement cancelled event
+pub(crate) fn arbiter_replacement_cancelled(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    new_arbiter: Address,
+) {
+    ArbiterReplacementCancelled {
+        escrow_id,
+        new_arbiter,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit arbiter replaced event
+pub(crate) fn arbiter_replaced(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    old_arbiter: Address,
+    new_arbiter: Address,
+) {
+    ArbiterReplaced {
+        escrow_id,
+        old_arbiter,
+        new_arbiter,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow cancelled event
+pub(crate) fn escrow_cancelled(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    cancelled_by: Address,
+    refunded: i128,
+) {
+    EscrowCancelled {
+        escrow_id,
+        depositor,
+        beneficiary,
+        cancelled_by,
+        refunded,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow refunded event
+pub(crate) fn escrow_refunded(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    amount: i128,
+) {
+    EscrowRefunded {
+        escrow_id,
+        depositor,
+        beneficiary,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute initiated event
+pub(crate) fn dispute_initiated(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    initiated_by: Address,
+    reason: String,
+) {
+    DisputeInitiated {
+        escrow_id,
+        depositor,
+        beneficiary,
+        initiated_by,
+        reason,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit evidence submitted event
+pub(crate) fn evidence_submitted(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    submitted_by: Address,
+    evidence_hash: String,
+) {
+    EvidenceSubmitted {
+        escrow_id,
+        submitted_by,
+        evidence_hash,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute escalated event
+pub(crate) fn dispute_escalated(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    to_panel: bool,
+) {
+    DisputeEscalated {
+        escrow_id,
+        depositor,
+        beneficiary,
+        to_panel,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute resolved event
+pub(crate) fn dispute_resolved(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    arbiter: Address,
+    release_to: Address,
+    amount: i128,
+    depositor_amount: i128,
+    beneficiary_amount: i128,
+) {
+    DisputeResolved {
+        escrow_id,
+        depositor,
+        beneficiary,
+        arbiter,
+        release_to,
+        amount,
+        depositor_amount,
+        beneficiary_amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit split proposed event
+pub(crate) fn split_proposed(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    proposed_by: Address,
+    depositor_amount: i128,
+    beneficiary_amount: i128,
+    deduction_reason: Option<String>,
+) {
+    SplitProposed {
+        escrow_id,
+        proposed_by,
+        depositor_amount,
+        beneficiary_amount,
+        deduction_reason,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit split approval revoked event
+pub(crate) fn split_approval_revoked(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    signer: Address,
+    beneficiary_amount: i128,
+) {
+    SplitApprovalRevoked {
+        escrow_id,
+        signer,
+        beneficiary_amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit split released event
+pub(crate) fn split_released(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    beneficiary: Address,
+    depositor_amount: i128,
+    beneficiary_amount: i128,
+    deduction_reason: Option<String>,
+) {
+    SplitReleased {
+        escrow_id,
+        depositor,
+        beneficiary,
+        depositor_amount,
+        beneficiary_amount,
+        deduction_reason,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit interest paid event
+pub(crate) fn interest_paid(env: &Env, escrow_id: BytesN<32>, depositor: Address, amount: i128) {
+    InterestPaid {
+        escrow_id,
+        depositor,
+        amount,
+    }
+    .publish(env);
+}
@@ -0,0 +1,68 @@
+//! Platform service fee, taken from funds released to the beneficiary and paid to
+//! the platform treasury. The rate is locked in on each escrow when it is created.
+use soroban_sdk::{token, Address, Env};
+
+use crate::access::AccessControl;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::interest::MAX_BPS;
+use crate::storage::EscrowStorage;
+use crate::types::{Escrow, PlatformFee};
+
+/// Highest platform fee the admin may configure (10%)
+pub const MAX_PLATFORM_FEE_BPS: u32 = 1_000;
+
+/// Platform fee configuration and collection.
+pub struct FeeHandler;
+
+impl FeeHandler {
+    /// Set the fee charged on escrows created from now on, and the treasury it is paid to
+    /// (admin only). A zero fee disables it.
+    pub fn set_platform_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        if fee_bps > MAX_PLATFORM_FEE_BPS {
+            return Err(EscrowError::InvalidPlatformFee);
+        }
+
+        EscrowStorage::set_platform_fee(&env, &PlatformFee { fee_bps, treasury });
+        Ok(())
+    }
+
+    /// Fee rate new escrows lock in (0 if no fee is configured).
+    pub(crate) fn current_fee_bps(env: &Env) -> u32 {
+        EscrowStorage::get_platform_fee(env).map_or(0, |fee| fee.fee_bps)
+    }
+
+    /// Pay `amount` of `token` out to the beneficiary, less the escrow's platform fee.
+    /// Returns the fee sent to the treasury.
+    pub(crate) fn pay_beneficiary(
+        env: &Env,
+        escrow: &Escrow,
+        token: &Address,
+        amount: i128,
+    ) -> i128 {
+        let token_client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+
+        let fee = amount * escrow.fee_bps as i128 / MAX_BPS as i128;
+        let treasury = EscrowStorage::get_platform_fee(env).map(|config| config.treasury);
+        let fee = match treasury {
+            Some(treasury) if fee > 0 => {
+                token_client.transfer(&contract, &treasury, &fee);
+                events::fee_collected(env, escrow.id.clone(), treasury, token.clone(), fee);
+                fee
+            }
+            _ => 0,
+        };
+
+        token_client.transfer(&contract, &escrow.beneficiary, &(amount - fee));
+        fee
+    }
+}
@@ -0,0 +1,508 @@
+//! Funding: instalments against an optional schedule, further token legs, amended
+//! deposit top-ups, and cancellation or expiry of escrows that never get fully funded.
+//! An escrow stays PartiallyFunded until `funded_amount` reaches `amount`.
+use soroban_sdk::{token, Address, BytesN, Env, Vec};
+
+use crate::access::AccessControl;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::interest::InterestHandler;
+use crate::storage::EscrowStorage;
+use crate::types::{AssetLeg, Escrow, EscrowStatus, FundingInstalment};
+
+/// Instalment funding and top-up handling.
+pub struct FundingHandler;
+
+impl FundingHandler {
+    /// Deposit `amount` towards an escrow.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or PartiallyFunded
+    /// - Caller must be depositor
+    /// - Amount must be positive and not exceed what is still owed
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer from depositor, measured by the contract's balance before and after
+    ///   so fee-on-transfer tokens are credited only with what arrived
+    ///
+    /// EFFECTS:
+    /// - Lock in the interest rate on the first deposit
+    /// - Increase `funded_amount` by the amount received; status becomes PartiallyFunded,
+    ///   or Funded once complete
+    pub fn deposit(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::PartiallyFunded
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        if amount <= 0 || amount > escrow.amount - escrow.funded_amount {
+            return Err(EscrowError::InvalidFundingAmount);
+        }
+
+        // INTERACTIONS: Token transfer from depositor to escrow contract. This runs before
+        // the state update because only the balance change says how much arrived.
+        let received = Self::receive(&env, &escrow.token, &caller, amount);
+        if received <= 0 {
+            return Err(EscrowError::InvalidFundingAmount);
+        }
+
+        // EFFECTS: Checkpoint interest on the old balance, then record the deposit
+        if escrow.status == EscrowStatus::Pending {
+            escrow.interest_rate_bps = EscrowStorage::get_interest_rate(&env);
+        }
+        InterestHandler::checkpoint(&env, &mut escrow);
+        escrow.funded_amount += received;
+        escrow.status = Self::funded_status(&escrow);
+        EscrowStorage::save(&env, &escrow);
+
+        if escrow.status == EscrowStatus::Funded {
+            events::escrow_funded(
+                &env,
+                escrow_id,
+                escrow.depositor,
+                escrow.beneficiary,
+                escrow.amount,
+            );
+        } else {
+            events::instalment_funded(
+                &env,
+                escrow_id,
+                escrow.depositor,
+                escrow.beneficiary,
+                received,
+                escrow.funded_amount,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Deposit whatever is still owed on the primary token and on every further leg.
+    pub fn fund_all(env: Env, escrow_id: BytesN<32>, caller: Address) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::PartiallyFunded
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        let remaining = escrow.amount - escrow.funded_amount;
+        if remaining > 0 {
+            Self::deposit(env.clone(), escrow_id.clone(), caller.clone(), remaining)?;
+        }
+        for leg in escrow.legs.iter() {
+            let remaining = leg.amount - leg.funded_amount;
+            if remaining > 0 {
+                Self::deposit_leg(
+                    env.clone(),
+                    escrow_id.clone(),
+                    caller.clone(),
+                    leg.token,
+                    remaining,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add a further (token, amount) leg to the escrow (depositor and beneficiary).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending
+    /// - Depositor and beneficiary must both authorize
+    /// - Amount must be positive
+    /// - The escrow must not already hold the token
+    ///
+    /// EFFECTS:
+    /// - Append the leg; the escrow is only Funded once every leg is
+    pub fn add_asset_leg(
+        env: Env,
+        escrow_id: BytesN<32>,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending {
+            return Err(EscrowError::InvalidState);
+        }
+
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        if amount <= 0 {
+            return Err(EscrowError::InvalidFundingAmount);
+        }
+
+        if token == escrow.token || escrow.legs.iter().any(|leg| leg.token == token) {
+            return Err(EscrowError::DuplicateAssetLeg);
+        }
+
+        // EFFECTS: Store the leg
+        escrow.legs.push_back(AssetLeg {
+            token,
+            amount,
+            funded_amount: 0,
+        });
+        EscrowStorage::save(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Deposit `amount` towards one of the escrow's further legs.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or PartiallyFunded
+    /// - Caller must be depositor
+    /// - The escrow must hold a leg in `token`
+    /// - Amount must be positive and not exceed what is still owed on that leg
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer from depositor, measured as in `deposit`
+    ///
+    /// EFFECTS:
+    /// - Increase the leg's `funded_amount`; status becomes PartiallyFunded, or Funded
+    ///   once every leg is complete
+    pub fn deposit_leg(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::PartiallyFunded
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        let index = escrow
+            .legs
+            .iter()
+            .position(|leg| leg.token == token)
+            .ok_or(EscrowError::AssetLegNotFound)? as u32;
+        let mut leg = escrow.legs.get_unchecked(index);
+
+        if amount <= 0 || amount > leg.amount - leg.funded_amount {
+            return Err(EscrowError::InvalidFundingAmount);
+        }
+
+        // INTERACTIONS: Token transfer from depositor to escrow contract
+        let received = Self::receive(&env, &token, &caller, amount);
+        if received <= 0 {
+            return Err(EscrowError::InvalidFundingAmount);
+        }
+
+        // EFFECTS: Record the deposit against the leg
+        if escrow.status == EscrowStatus::Pending {
+            escrow.interest_rate_bps = EscrowStorage::get_interest_rate(&env);
+        }
+        leg.funded_amount += received;
+        let leg_funded = leg.funded_amount;
+        escrow.legs.set(index, leg);
+        escrow.status = Self::funded_status(&escrow);
+        EscrowStorage::save(&env, &escrow);
+
+        events::leg_funded(
+            &env,
+            escrow_id.clone(),
+            escrow.depositor.clone(),
+            escrow.beneficiary.clone(),
+            token,
+            received,
+            leg_funded,
+        );
+        if escrow.status == EscrowStatus::Funded {
+            events::escrow_funded(
+                &env,
+                escrow_id,
+                escrow.depositor,
+                escrow.beneficiary,
+                escrow.amount,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Funded once the primary token and every further leg are fully deposited.
+    fn funded_status(escrow: &Escrow) -> EscrowStatus {
+        let legs_funded = escrow
+            .legs
+            .iter()
+            .all(|leg| leg.funded_amount >= leg.amount);
+        if escrow.funded_amount >= escrow.amount && legs_funded {
+            EscrowStatus::Funded
+        } else {
+            EscrowStatus::PartiallyFunded
+        }
+    }
+
+    /// Transfer `amount` of `token` from `from` to this contract and return how much the
+    /// contract's balance actually grew by.
+    pub(crate) fn receive(env: &Env, token: &Address, from: &Address, amount: i128) -> i128 {
+        let token_client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        let before = token_client.balance(&contract);
+        token_client.transfer(from, &contract, &amount);
+        token_client.balance(&contract) - before
+    }
+
+    /// Agree a funding schedule for the deposit (depositor and beneficiary).
+    /// Instalments must be positive, in due-date order and add up to `amount`.
+    pub fn set_funding_schedule(
+        env: Env,
+        escrow_id: BytesN<32>,
+        instalments: Vec<FundingInstalment>,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::PartiallyFunded
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        if instalments.is_empty() {
+            return Err(EscrowError::InvalidSchedule);
+        }
+
+        let mut total: i128 = 0;
+        let mut last_due: u64 = 0;
+        for instalment in instalments.iter() {
+            if instalment.amount <= 0 || instalment.due_date < last_due {
+                return Err(EscrowError::InvalidSchedule);
+            }
+            total += instalment.amount;
+            last_due = instalment.due_date;
+        }
+        if total != escrow.amount {
+            return Err(EscrowError::InvalidSchedule);
+        }
+
+        // EFFECTS: Store the schedule
+        EscrowStorage::set_funding_schedule(&env, &escrow_id, &instalments);
+
+        Ok(())
+    }
+
+    /// Amount the schedule says should have been deposited by now but has not been.
+    pub fn funding_due(env: &Env, escrow_id: &BytesN<32>) -> Result<i128, EscrowError> {
+        let escrow = EscrowStorage::get(env, escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        let now = env.ledger().timestamp();
+        let mut scheduled: i128 = 0;
+        for instalment in EscrowStorage::get_funding_schedule(env, escrow_id).iter() {
+            if instalment.due_date <= now {
+                scheduled += instalment.amount;
+            }
+        }
+
+        Ok((scheduled - escrow.funded_amount).max(0))
+    }
+
+    /// Raise the deposit after the agreement is amended (depositor and beneficiary).
+    /// A Funded escrow drops back to PartiallyFunded until the difference is deposited;
+    /// the funding deadline it already met no longer applies, so it cannot be expired
+    /// unless the parties agree a new one.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending, PartiallyFunded or Funded
+    /// - Both depositor and beneficiary must authorize
+    /// - New amount must exceed the current amount
+    ///
+    /// EFFECTS:
+    /// - Update `amount`, drop any funding schedule and withdraw pending approvals and
+    ///   split proposals, recording each in the approval history
+    /// - Clear the funding deadline of a Funded escrow
+    pub fn increase_amount(
+        env: Env,
+        escrow_id: BytesN<32>,
+        new_amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if !matches!(
+            escrow.status,
+            EscrowStatus::Pending | EscrowStatus::PartiallyFunded | EscrowStatus::Funded
+        ) {
+            return Err(EscrowError::InvalidState);
+        }
+
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        if new_amount <= escrow.amount {
+            return Err(EscrowError::InvalidFundingAmount);
+        }
+
+        // EFFECTS: Update the amount; approvals given for the old amount no longer apply
+        let old_amount = escrow.amount;
+        InterestHandler::checkpoint(&env, &mut escrow);
+        escrow.amount = new_amount;
+        if escrow.status == EscrowStatus::Funded {
+            escrow.status = EscrowStatus::PartiallyFunded;
+            escrow.funding_deadline = None;
+        }
+        EscrowStorage::save(&env, &escrow);
+        EscrowStorage::clear_funding_schedule(&env, &escrow_id);
+        EscrowStorage::withdraw_approvals(&env, &escrow);
+
+        events::amount_increased(
+            &env,
+            escrow_id,
+            escrow.depositor,
+            escrow.beneficiary,
+            old_amount,
+            new_amount,
+        );
+
+        Ok(())
+    }
+
+    /// Agree a deadline for fully funding the escrow (depositor and beneficiary).
+    /// Once it passes, anyone may expire the escrow.
+    pub fn set_funding_deadline(
+        env: Env,
+        escrow_id: BytesN<32>,
+        deadline: u64,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::PartiallyFunded
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        if deadline <= env.ledger().timestamp() {
+            return Err(EscrowError::InvalidFundingDeadline);
+        }
+
+        // EFFECTS: Store the deadline
+        escrow.funding_deadline = Some(deadline);
+        EscrowStorage::save(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Cancel an escrow nobody has funded yet (depositor or beneficiary).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending
+    /// - Caller must be depositor or beneficiary
+    ///
+    /// EFFECTS:
+    /// - Status becomes Cancelled; the escrow no longer counts as live
+    pub fn cancel(env: Env, escrow_id: BytesN<32>, caller: Address) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_primary_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        Self::close(&env, escrow, caller);
+
+        Ok(())
+    }
+
+    /// Expire an escrow that missed its funding deadline. Anyone may call.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or PartiallyFunded
+    /// - Funding deadline must be set and have passed
+    ///
+    /// EFFECTS:
+    /// - Status becomes Cancelled; the escrow no longer counts as live
+    ///
+    /// INTERACTIONS:
+    /// - Return any instalments already deposited, in every token and with their
+    ///   interest, to the depositor
+    pub fn expire(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::PartiallyFunded
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        match escrow.funding_deadline {
+            Some(deadline) if env.ledger().timestamp() > deadline => {}
+            _ => return Err(EscrowError::FundingDeadlineNotReached),
+        }
+
+        Self::close(&env, escrow, env.current_contract_address());
+
+        Ok(())
+    }
+
+    /// Mark an unfunded or partially funded escrow Cancelled and hand back what was deposited.
+    fn close(env: &Env, mut escrow: Escrow, cancelled_by: Address) {
+        // EFFECTS: Update status and drop the schedule
+        let held = escrow.clone();
+        let refunded = escrow.funded_amount;
+        escrow.status = EscrowStatus::Cancelled;
+        EscrowStorage::save(env, &escrow);
+        EscrowStorage::clear_funding_schedule(env, &escrow.id);
+        EscrowStorage::decrement_count(env);
+
+        // INTERACTIONS: Return partial deposits, in every token, to the depositor
+        let contract = env.current_contract_address();
+        if refunded > 0 {
+            let token_client = token::Client::new(env, &escrow.token);
+            token_client.transfer(&contract, &escrow.depositor, &refunded);
+            InterestHandler::pay_interest(env, &held);
+        }
+        for leg in escrow.legs.iter() {
+            if leg.funded_amount > 0 {
+                let token_client = token::Client::new(env, &leg.token);
+                token_client.transfer(&contract, &escrow.depositor, &leg.funded_amount);
+            }
+        }
+
+        events::escrow_cancelled(
+            env,
+            escrow.id,
+            escrow.depositor,
+            escrow.beneficiary,
+            cancelled_by,
+            refunded,
+        );
+    }
+}
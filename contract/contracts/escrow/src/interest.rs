@@ -0,0 +1,121 @@
+//! Interest on held deposits, paid to the depositor from a platform-funded reserve.
+//! Rates are annual basis points, locked in on each escrow when it is funded.
+use soroban_sdk::{token, Address, Env};
+
+use crate::access::AccessControl;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::funding::FundingHandler;
+use crate::storage::EscrowStorage;
+use crate::types::{Escrow, EscrowStatus};
+
+/// Basis-point denominator (10_000 = 100%)
+pub const MAX_BPS: u32 = 10_000;
+
+/// Seconds in a 365-day year, used to pro-rate annual rates
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Interest configuration, accrual and payout.
+pub struct InterestHandler;
+
+impl InterestHandler {
+    /// Set the annual interest rate applied to escrows funded from now on (admin only).
+    pub fn set_interest_rate(env: Env, admin: Address, rate_bps: u32) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        if rate_bps > MAX_BPS {
+            return Err(EscrowError::InvalidInterestRate);
+        }
+
+        EscrowStorage::set_interest_rate(&env, rate_bps);
+        Ok(())
+    }
+
+    /// Top up the interest reserve for a token from `funder`.
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer from funder to the escrow contract; the reserve is credited
+    ///   with what actually arrived
+    pub fn fund_interest_reserve(
+        env: Env,
+        funder: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        if amount <= 0 {
+            return Err(EscrowError::InsufficientFunds);
+        }
+
+        funder.require_auth();
+
+        // INTERACTIONS: Pull the funds in, measuring what arrived
+        let received = FundingHandler::receive(&env, &token, &funder, amount);
+
+        // EFFECTS: Credit the reserve
+        let reserve = EscrowStorage::get_interest_reserve(&env, &token);
+        EscrowStorage::set_interest_reserve(&env, &token, reserve + received.max(0));
+
+        Ok(())
+    }
+
+    /// Interest accrued on an escrow so far (simple interest, rounded down).
+    /// Escrows accrue on their deposited balance while funds are held, i.e. while
+    /// PartiallyFunded, Funded or Disputed.
+    pub fn accrued_interest(env: &Env, escrow: &Escrow) -> i128 {
+        if !matches!(
+            escrow.status,
+            EscrowStatus::PartiallyFunded | EscrowStatus::Funded | EscrowStatus::Disputed
+        ) {
+            return 0;
+        }
+
+        let funded_at = match escrow.funded_at {
+            Some(funded_at) => funded_at,
+            None => return escrow.interest_accrued,
+        };
+
+        let elapsed = env.ledger().timestamp().saturating_sub(funded_at);
+        escrow.interest_accrued
+            + escrow.funded_amount * escrow.interest_rate_bps as i128 * elapsed as i128
+                / (MAX_BPS as i128 * SECONDS_PER_YEAR as i128)
+    }
+
+    /// Fold interest accrued on the current balance into `interest_accrued` and restart
+    /// accrual from now. Call before changing `funded_amount`.
+    pub(crate) fn checkpoint(env: &Env, escrow: &mut Escrow) {
+        escrow.interest_accrued = Self::accrued_interest(env, escrow);
+        escrow.funded_at = Some(env.ledger().timestamp());
+    }
+
+    /// Pay the accrued interest to the depositor, capped at what the reserve holds.
+    /// Must be called with the escrow as it was before settlement.
+    ///
+    /// EFFECTS:
+    /// - Debit the reserve
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer to the depositor after state update
+    pub(crate) fn pay_interest(env: &Env, escrow: &Escrow) -> i128 {
+        let reserve = EscrowStorage::get_interest_reserve(env, &escrow.token);
+        let interest = Self::accrued_interest(env, escrow).min(reserve);
+        if interest <= 0 {
+            return 0;
+        }
+
+        // EFFECTS: Debit the reserve
+        EscrowStorage::set_interest_reserve(env, &escrow.token, reserve - interest);
+
+        // INTERACTIONS: Token transfer to the depositor
+        let token_client = token::Client::new(env, &escrow.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &escrow.depositor,
+            &interest,
+        );
+
+        events::interest_paid(env, escrow.id.clone(), escrow.depositor.clone(), interest);
+
+        interest
+    }
+}
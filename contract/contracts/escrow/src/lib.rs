@@ -3,13 +3,23 @@
 
 //! Escrow Contract
 //!
-//! Manages security deposit escrows with 2-of-3 multi-sig release mechanism.
-//! Supports dispute resolution with arbiter involvement.
+//! Manages security deposit escrows with a weighted M-of-N multi-sig release
+//! mechanism (2-of-3 by default).
+//! Supports dispute resolution with arbiter involvement, optionally delegated to the
+//! dispute_resolution arbiter panel.
+//! Held deposits can accrue interest for the depositor from a platform reserve, and
+//! releases can carry a platform service fee.
 
 pub mod access;
 pub mod dispute;
 pub mod errors;
 pub mod escrow_impl;
+pub mod events;
+pub mod fees;
+pub mod funding;
+pub mod interest;
+pub mod recovery;
+pub mod release;
 pub mod storage;
 pub mod types;
 
@@ -21,5 +31,14 @@ pub use access::AccessControl;
 pub use dispute::DisputeHandler;
 pub use errors::EscrowError;
 pub use escrow_impl::EscrowContract;
+pub use fees::FeeHandler;
+pub use funding::FundingHandler;
+pub use interest::InterestHandler;
+pub use recovery::RecoveryHandler;
+pub use release::ReleaseHandler;
 pub use storage::EscrowStorage;
-pub use types::{DataKey, Escrow, EscrowStatus, ReleaseApproval};
+pub use types::{
+    ApprovalAction, ApprovalRecord, ArbiterReplacement, AssetLeg, DataKey, DisputeRecord, Escrow,
+    EscrowStatus, EvidenceEntry, FundingInstalment, PanelDispute, PartyRole, PlatformFee,
+    ReleaseApproval, ReleaseProposal, Signer,
+};
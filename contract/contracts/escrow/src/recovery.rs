@@ -0,0 +1,163 @@
+//! Arbiter recovery: the admin can replace a lost or unresponsive arbiter on a disputed
+//! escrow, where funds cannot move without one, but only after a public delay. The admin
+//! never moves funds; the replacement arbiter is bound by the same rules as the original,
+//! so funds can still only go to the escrow's depositor or beneficiary.
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::access::AccessControl;
+use crate::dispute::DEFAULT_RESOLUTION_WINDOW;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::storage::EscrowStorage;
+use crate::types::{ArbiterReplacement, Escrow, EscrowStatus};
+
+/// Seconds between announcing an arbiter replacement and executing it (7 days)
+pub const ARBITER_REPLACEMENT_DELAY: u64 = 604_800;
+
+/// Timelocked arbiter replacement.
+pub struct RecoveryHandler;
+
+impl RecoveryHandler {
+    /// Announce that an escrow's arbiter will be replaced (admin only).
+    /// Announcing again restarts the delay with the new arbiter.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Disputed
+    /// - Caller must be admin
+    /// - New arbiter must be platform-approved and not already a party or signer
+    ///
+    /// EFFECTS:
+    /// - Store the pending replacement, executable after `ARBITER_REPLACEMENT_DELAY`
+    pub fn propose_arbiter_replacement(
+        env: Env,
+        admin: Address,
+        escrow_id: BytesN<32>,
+        new_arbiter: Address,
+    ) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Self::check_replaceable(&escrow)?;
+
+        if !EscrowStorage::is_approved_arbiter(&env, &new_arbiter) {
+            return Err(EscrowError::ArbiterNotApproved);
+        }
+
+        if new_arbiter == escrow.depositor
+            || new_arbiter == escrow.beneficiary
+            || escrow.signers.iter().any(|s| s.address == new_arbiter)
+        {
+            return Err(EscrowError::InvalidSigner);
+        }
+
+        // EFFECTS: Store the pending replacement
+        let executable_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(ARBITER_REPLACEMENT_DELAY);
+        EscrowStorage::set_arbiter_replacement(
+            &env,
+            &escrow_id,
+            &ArbiterReplacement {
+                new_arbiter: new_arbiter.clone(),
+                proposed_at: env.ledger().timestamp(),
+                executable_at,
+            },
+        );
+
+        events::arbiter_replacement_proposed(
+            &env,
+            escrow_id,
+            escrow.arbiter,
+            new_arbiter,
+            executable_at,
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw a pending arbiter replacement (admin only).
+    pub fn cancel_arbiter_replacement(
+        env: Env,
+        admin: Address,
+        escrow_id: BytesN<32>,
+    ) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        let replacement = EscrowStorage::get_arbiter_replacement(&env, &escrow_id)
+            .ok_or(EscrowError::ReplacementNotFound)?;
+
+        EscrowStorage::clear_arbiter_replacement(&env, &escrow_id);
+
+        events::arbiter_replacement_cancelled(&env, escrow_id, replacement.new_arbiter);
+
+        Ok(())
+    }
+
+    /// Carry out an announced arbiter replacement once its delay has passed. Anyone may call.
+    ///
+    /// CHECKS:
+    /// - A replacement must be pending and its delay must have passed
+    /// - Escrow must still be Disputed
+    ///
+    /// EFFECTS:
+    /// - Swap the arbiter, keeping its signer weight
+    /// - Withdraw pending approvals and split proposals, which may include the old
+    ///   arbiter's, recording each in the approval history
+    /// - Give the dispute a fresh resolution deadline for the new arbiter
+    pub fn execute_arbiter_replacement(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate the pending replacement
+        let replacement = EscrowStorage::get_arbiter_replacement(&env, &escrow_id)
+            .ok_or(EscrowError::ReplacementNotFound)?;
+
+        let now = env.ledger().timestamp();
+        if now < replacement.executable_at {
+            return Err(EscrowError::ReplacementTimelocked);
+        }
+
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Self::check_replaceable(&escrow)?;
+
+        // EFFECTS: Withdraw approvals under the old signer set, then swap the arbiter
+        EscrowStorage::withdraw_approvals(&env, &escrow);
+        EscrowStorage::unindex_escrow(&env, &escrow);
+
+        let old_arbiter = escrow.arbiter.clone();
+        let new_arbiter = replacement.new_arbiter;
+        for i in 0..escrow.signers.len() {
+            let mut signer = escrow.signers.get_unchecked(i);
+            if signer.address == old_arbiter {
+                signer.address = new_arbiter.clone();
+                escrow.signers.set(i, signer);
+            }
+        }
+        escrow.arbiter = new_arbiter.clone();
+        EscrowStorage::save(&env, &escrow);
+        EscrowStorage::index_escrow(&env, &escrow);
+        EscrowStorage::clear_arbiter_replacement(&env, &escrow_id);
+
+        if let Some(mut record) = EscrowStorage::get_dispute_record(&env, &escrow_id) {
+            let (_, resolution_window) =
+                EscrowStorage::get_dispute_windows(&env).unwrap_or((0, DEFAULT_RESOLUTION_WINDOW));
+            record.resolution_deadline = now.saturating_add(resolution_window);
+            EscrowStorage::set_dispute_record(&env, &escrow_id, &record);
+        }
+
+        events::arbiter_replaced(&env, escrow_id, old_arbiter, new_arbiter);
+
+        Ok(())
+    }
+
+    /// Only a disputed escrow is stranded without its arbiter: in every other state the
+    /// depositor and beneficiary can settle or cancel between them.
+    fn check_replaceable(escrow: &Escrow) -> Result<(), EscrowError> {
+        if escrow.status == EscrowStatus::Disputed {
+            Ok(())
+        } else {
+            Err(EscrowError::InvalidState)
+        }
+    }
+}
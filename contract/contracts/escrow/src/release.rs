@@ -0,0 +1,481 @@
+//! Split releases: dividing the escrowed amount between depositor and beneficiary.
+//! Signers meeting the escrow's approval threshold must approve the exact same split
+//! before funds move. Splits are stated in the primary token; further token legs are
+//! divided in the same proportion.
+//! An optional claim window lets the depositor reclaim the deposit alone once the
+//! beneficiary's deadline for filing deductions has passed.
+use soroban_sdk::{token, Address, BytesN, Env, String, Vec};
+
+use crate::access::AccessControl;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::fees::FeeHandler;
+use crate::interest::InterestHandler;
+use crate::storage::EscrowStorage;
+use crate::types::{ApprovalAction, ApprovalRecord, Escrow, EscrowStatus, ReleaseProposal};
+
+/// Split proposal handling and execution.
+pub struct ReleaseHandler;
+
+impl ReleaseHandler {
+    /// Validate a split of the escrowed amount and return `(depositor_amount, beneficiary_amount)`.
+    pub fn split_amounts(
+        escrow: &Escrow,
+        beneficiary_amount: i128,
+    ) -> Result<(i128, i128), EscrowError> {
+        if beneficiary_amount < 0 || beneficiary_amount > escrow.amount {
+            return Err(EscrowError::InvalidSplit);
+        }
+        Ok((escrow.amount - beneficiary_amount, beneficiary_amount))
+    }
+
+    /// End of the claim window (`release_date + claim_window`), if one is configured.
+    pub fn claim_deadline(escrow: &Escrow) -> Option<u64> {
+        escrow
+            .release_date
+            .map(|date| date.saturating_add(escrow.claim_window))
+    }
+
+    /// Reject deductions filed by the beneficiary after the claim window has closed.
+    pub fn check_deduction_deadline(
+        env: &Env,
+        escrow: &Escrow,
+        caller: &Address,
+    ) -> Result<(), EscrowError> {
+        if *caller != escrow.beneficiary {
+            return Ok(());
+        }
+        match Self::claim_deadline(escrow) {
+            Some(deadline) if env.ledger().timestamp() >= deadline => {
+                Err(EscrowError::ClaimWindowClosed)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Configure the release date and claim window of an escrow.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or Funded
+    /// - Both depositor and beneficiary must authorize
+    /// - Claim window must be non-zero
+    ///
+    /// EFFECTS:
+    /// - Store the release date and window on the escrow
+    pub fn set_claim_window(
+        env: Env,
+        escrow_id: BytesN<32>,
+        release_date: u64,
+        claim_window: u64,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        if claim_window == 0 {
+            return Err(EscrowError::NoClaimWindow);
+        }
+
+        // EFFECTS: Store the window
+        escrow.release_date = Some(release_date);
+        escrow.claim_window = claim_window;
+        EscrowStorage::save(&env, &escrow);
+
+        Ok(())
+    }
+
+    /// Return the full deposit to the depositor once the claim window has elapsed
+    /// without a dispute (depositor only, no other approvals needed).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded (not Disputed)
+    /// - Caller must be depositor
+    /// - Claim window must be configured and elapsed
+    /// - No split the beneficiary proposed before the deadline may still be pending
+    ///
+    /// EFFECTS:
+    /// - Mark the escrow Refunded and clear pending approvals
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer to the depositor after state update
+    pub fn claim_refund(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(EscrowError::DisputeActive);
+        }
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        let deadline = Self::claim_deadline(&escrow).ok_or(EscrowError::NoClaimWindow)?;
+        if env.ledger().timestamp() < deadline {
+            return Err(EscrowError::ClaimWindowOpen);
+        }
+
+        // Deductions filed in time must be settled by agreement or dispute
+        if let Some(proposal) = EscrowStorage::get_proposal(&env, &escrow_id) {
+            if proposal.proposed_by == escrow.beneficiary && proposal.proposed_at < deadline {
+                return Err(EscrowError::DeductionPending);
+            }
+        }
+
+        // EFFECTS + INTERACTIONS
+        let amount = escrow.amount;
+        Self::execute_split(&env, escrow, amount, 0, None);
+
+        Ok(())
+    }
+
+    /// Pay out a settled escrow: the primary token as given, and every further leg
+    /// divided in the same proportion. The beneficiary's shares carry the platform fee.
+    pub(crate) fn pay_shares(
+        env: &Env,
+        escrow: &Escrow,
+        depositor_amount: i128,
+        beneficiary_amount: i128,
+    ) {
+        Self::pay_leg(
+            env,
+            escrow,
+            &escrow.token,
+            depositor_amount,
+            beneficiary_amount,
+        );
+        for leg in escrow.legs.iter() {
+            let to_beneficiary = leg.amount * beneficiary_amount / escrow.amount;
+            Self::pay_leg(
+                env,
+                escrow,
+                &leg.token,
+                leg.amount - to_beneficiary,
+                to_beneficiary,
+            );
+        }
+    }
+
+    fn pay_leg(
+        env: &Env,
+        escrow: &Escrow,
+        token: &Address,
+        depositor_amount: i128,
+        beneficiary_amount: i128,
+    ) {
+        if depositor_amount > 0 {
+            let token_client = token::Client::new(env, token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &depositor_amount,
+            );
+        }
+        if beneficiary_amount > 0 {
+            FeeHandler::pay_beneficiary(env, escrow, token, beneficiary_amount);
+        }
+    }
+
+    /// Propose dividing the escrow between depositor and beneficiary.
+    /// Replaces any earlier proposal, withdrawing the approvals it had collected;
+    /// the proposer's approval is recorded.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded
+    /// - Caller must be a valid party
+    /// - Split must not exceed the escrowed amount
+    /// - A beneficiary may not propose after the claim window has closed
+    ///
+    /// EFFECTS:
+    /// - Record a revocation in the approval history for every approval of the
+    ///   replaced proposal
+    /// - Store the proposal with the proposer as first approver, and record that approval
+    /// - Settle immediately if the proposer's weight alone meets the threshold
+    pub fn propose_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+        deduction_reason: Option<String>,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        Self::check_deduction_deadline(&env, &escrow, &caller)?;
+
+        let (depositor_amount, beneficiary_amount) =
+            Self::split_amounts(&escrow, beneficiary_amount)?;
+
+        // EFFECTS: Withdraw the approvals of the split being replaced, then store the proposal
+        if let Some(previous) = EscrowStorage::get_proposal(&env, &escrow_id) {
+            for signer in previous.approvals.iter() {
+                Self::record_split_action(
+                    &env,
+                    &escrow,
+                    signer.clone(),
+                    ApprovalAction::Revoked,
+                    previous.beneficiary_amount,
+                );
+                events::split_approval_revoked(
+                    &env,
+                    escrow_id.clone(),
+                    signer,
+                    previous.beneficiary_amount,
+                );
+            }
+        }
+
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(caller.clone());
+
+        let proposal = ReleaseProposal {
+            depositor_amount,
+            beneficiary_amount,
+            deduction_reason: deduction_reason.clone(),
+            proposed_by: caller.clone(),
+            proposed_at: env.ledger().timestamp(),
+            approvals,
+        };
+        EscrowStorage::set_proposal(&env, &escrow_id, &proposal);
+        Self::record_split_action(
+            &env,
+            &escrow,
+            caller.clone(),
+            ApprovalAction::Approved,
+            beneficiary_amount,
+        );
+
+        events::split_proposed(
+            &env,
+            escrow_id,
+            caller,
+            depositor_amount,
+            beneficiary_amount,
+            deduction_reason.clone(),
+        );
+
+        // A signer heavy enough to meet the threshold alone settles immediately
+        if AccessControl::approval_weight(&escrow, &proposal.approvals) >= escrow.threshold {
+            Self::execute_split(
+                &env,
+                escrow,
+                depositor_amount,
+                beneficiary_amount,
+                deduction_reason,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Approve the pending split. The caller restates the beneficiary amount so an
+    /// approval can only ever apply to the exact split the caller saw.
+    /// Executes the split once the approving signers reach the escrow's threshold.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded
+    /// - Caller must be a valid party
+    /// - Pending proposal must match `beneficiary_amount`
+    /// - Caller must not have already approved it
+    ///
+    /// EFFECTS:
+    /// - Record approval, in the proposal and the approval history; once the threshold
+    ///   is met settle the escrow
+    ///
+    /// INTERACTIONS:
+    /// - Token transfers after all state updates
+    pub fn approve_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        let mut proposal =
+            EscrowStorage::get_proposal(&env, &escrow_id).ok_or(EscrowError::ProposalMismatch)?;
+
+        if proposal.beneficiary_amount != beneficiary_amount {
+            return Err(EscrowError::ProposalMismatch);
+        }
+
+        if proposal.approvals.contains(&caller) {
+            return Err(EscrowError::AlreadySigned);
+        }
+
+        // EFFECTS: Record the approval
+        proposal.approvals.push_back(caller.clone());
+        Self::record_split_action(
+            &env,
+            &escrow,
+            caller,
+            ApprovalAction::Approved,
+            beneficiary_amount,
+        );
+
+        if AccessControl::approval_weight(&escrow, &proposal.approvals) < escrow.threshold {
+            EscrowStorage::set_proposal(&env, &escrow_id, &proposal);
+            return Ok(());
+        }
+
+        Self::execute_split(
+            &env,
+            escrow,
+            proposal.depositor_amount,
+            proposal.beneficiary_amount,
+            proposal.deduction_reason,
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw an approval of the pending split before it takes effect.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded
+    /// - Caller must be a valid party
+    /// - Caller must have approved the pending split
+    ///
+    /// EFFECTS:
+    /// - Remove the caller from the proposal's approvals
+    /// - Append a revocation to the approval history
+    pub fn revoke_split_approval(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        AccessControl::is_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        caller.require_auth();
+
+        let mut proposal =
+            EscrowStorage::get_proposal(&env, &escrow_id).ok_or(EscrowError::ApprovalNotFound)?;
+        let index = proposal
+            .approvals
+            .first_index_of(&caller)
+            .ok_or(EscrowError::ApprovalNotFound)?;
+
+        // EFFECTS: Withdraw the approval and record the revocation
+        proposal.approvals.remove(index);
+        EscrowStorage::set_proposal(&env, &escrow_id, &proposal);
+        Self::record_split_action(
+            &env,
+            &escrow,
+            caller.clone(),
+            ApprovalAction::Revoked,
+            proposal.beneficiary_amount,
+        );
+
+        events::split_approval_revoked(&env, escrow_id, caller, proposal.beneficiary_amount);
+
+        Ok(())
+    }
+
+    /// Append a split approval or revocation to the approval history. Splits are
+    /// recorded against the beneficiary, with the beneficiary's share.
+    fn record_split_action(
+        env: &Env,
+        escrow: &Escrow,
+        signer: Address,
+        action: ApprovalAction,
+        beneficiary_amount: i128,
+    ) {
+        EscrowStorage::append_approval_history(
+            env,
+            &escrow.id,
+            ApprovalRecord {
+                signer,
+                release_to: escrow.beneficiary.clone(),
+                action,
+                beneficiary_amount: Some(beneficiary_amount),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Mark the escrow Released (or Refunded when the beneficiary gets nothing),
+    /// clear pending approvals and pay out both shares plus the depositor's interest.
+    /// Callers must have validated the split against the escrowed amount.
+    pub(crate) fn execute_split(
+        env: &Env,
+        mut escrow: Escrow,
+        depositor_amount: i128,
+        beneficiary_amount: i128,
+        deduction_reason: Option<String>,
+    ) {
+        // EFFECTS: Update status and clear pending approvals.
+        // A split that returns everything to the depositor is a refund.
+        let held = escrow.clone();
+        escrow.status = if beneficiary_amount == 0 {
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::Released
+        };
+        escrow.dispute_reason = None;
+        EscrowStorage::save(env, &escrow);
+        EscrowStorage::clear_proposal(env, &escrow.id);
+        EscrowStorage::clear_approvals(env, &escrow.id);
+        EscrowStorage::clear_approval_counts(env, &escrow);
+
+        // INTERACTIONS: Token transfers from escrow contract to both parties
+        Self::pay_shares(env, &escrow, depositor_amount, beneficiary_amount);
+        InterestHandler::pay_interest(env, &held);
+
+        if beneficiary_amount == 0 {
+            events::escrow_refunded(
+                env,
+                escrow.id,
+                escrow.depositor,
+                escrow.beneficiary,
+                depositor_amount,
+            );
+        } else {
+            events::split_released(
+                env,
+                escrow.id,
+                escrow.depositor,
+                escrow.beneficiary,
+                depositor_amount,
+                beneficiary_amount,
+                deduction_reason,
+            );
+        }
+    }
+}
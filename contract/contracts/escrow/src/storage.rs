@@ -2,7 +2,54 @@
 //! Implements single-responsibility getter/setter helpers.
 use soroban_sdk::{Address, BytesN, Env, Vec};
 
-use crate::types::{DataKey, Escrow, ReleaseApproval};
+use crate::types::{
+    ApprovalAction, ApprovalRecord, ArbiterReplacement, DataKey, DisputeRecord, Escrow,
+    EscrowStatus, EvidenceEntry, FundingInstalment, PartyRole, PlatformFee, ReleaseApproval,
+    ReleaseProposal,
+};
+
+/// One of a party's escrow indexes: every escrow it plays `role` in, or only those in
+/// one status.
+enum Listing {
+    Party(PartyRole, Address),
+    Status(PartyRole, Address, EscrowStatus),
+}
+
+impl Listing {
+    fn len_key(&self) -> DataKey {
+        match self {
+            Listing::Party(role, party) => DataKey::PartyIndexLen(role.clone(), party.clone()),
+            Listing::Status(role, party, status) => {
+                DataKey::StatusIndexLen(role.clone(), party.clone(), status.clone())
+            }
+        }
+    }
+
+    fn entry_key(&self, position: u32) -> DataKey {
+        match self {
+            Listing::Party(role, party) => {
+                DataKey::PartyIndexEntry(role.clone(), party.clone(), position)
+            }
+            Listing::Status(role, party, status) => {
+                DataKey::StatusIndexEntry(role.clone(), party.clone(), status.clone(), position)
+            }
+        }
+    }
+
+    fn slot_key(&self, escrow_id: &BytesN<32>) -> DataKey {
+        match self {
+            Listing::Party(role, party) => {
+                DataKey::PartyIndexSlot(role.clone(), party.clone(), escrow_id.clone())
+            }
+            Listing::Status(role, party, status) => DataKey::StatusIndexSlot(
+                role.clone(),
+                party.clone(),
+                status.clone(),
+                escrow_id.clone(),
+            ),
+        }
+    }
+}
 
 /// Escrow storage management.
 pub struct EscrowStorage;
@@ -16,9 +63,15 @@ impl EscrowStorage {
     }
 
     /// Save or update an escrow.
-    /// Updates existing escrow or creates a new one.
+    /// Updates existing escrow or creates a new one, moving it between per-status
+    /// indexes when its status changes.
     pub fn save(env: &Env, escrow: &Escrow) {
         let key = DataKey::Escrow(escrow.id.clone());
+        if let Some(previous) = env.storage().persistent().get::<_, Escrow>(&key) {
+            if previous.status != escrow.status {
+                Self::reindex_status(env, escrow, &previous.status);
+            }
+        }
         env.storage().persistent().set(&key, escrow);
     }
 
@@ -45,6 +98,64 @@ impl EscrowStorage {
         env.storage().persistent().set(&key, &approvals);
     }
 
+    /// Remove a signer's active approval for a target.
+    pub fn remove_approval(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+        signer: &Address,
+        release_to: &Address,
+    ) {
+        let approvals = Self::get_approvals(env, escrow_id);
+        let mut remaining = Vec::new(env);
+        for approval in approvals.iter() {
+            if approval.signer != *signer || approval.release_to != *release_to {
+                remaining.push_back(approval);
+            }
+        }
+        let key = DataKey::Approvals(escrow_id.clone());
+        env.storage().persistent().set(&key, &remaining);
+    }
+
+    /// Number of records in an escrow's approval history.
+    pub fn approval_history_len(env: &Env, escrow_id: &BytesN<32>) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::ApprovalHistoryLen(escrow_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Read up to `limit` approval history records from `start`, oldest first.
+    /// Unlike the active approvals, the history is never cleared while the escrow lives.
+    pub fn get_approval_history(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ApprovalRecord> {
+        let len = Self::approval_history_len(env, escrow_id);
+        let mut page = Vec::new(env);
+        for position in start..len.min(start.saturating_add(limit)) {
+            let key = DataKey::ApprovalHistoryEntry(escrow_id.clone(), position);
+            if let Some(record) = env.storage().persistent().get::<_, ApprovalRecord>(&key) {
+                page.push_back(record);
+            }
+        }
+        page
+    }
+
+    /// Append an entry to the approval history. Each record is its own ledger entry,
+    /// so the cost of a write does not grow with the history.
+    pub fn append_approval_history(env: &Env, escrow_id: &BytesN<32>, record: ApprovalRecord) {
+        let len = Self::approval_history_len(env, escrow_id);
+        env.storage().persistent().set(
+            &DataKey::ApprovalHistoryEntry(escrow_id.clone(), len),
+            &record,
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::ApprovalHistoryLen(escrow_id.clone()), &(len + 1));
+    }
+
     /// Clear all approvals for an escrow.
     /// Also clears per-target counts and per-signer flags.
     pub fn clear_approvals(env: &Env, escrow_id: &BytesN<32>) {
@@ -52,8 +163,47 @@ impl EscrowStorage {
         env.storage().persistent().remove(&key);
     }
 
-    /// Get the approval count for a specific release target (O(1) lookup).
-    pub fn get_approval_count_for_target(
+    /// Drop every pending approval, release and split alike, recording each one as
+    /// revoked in the approval history.
+    pub fn withdraw_approvals(env: &Env, escrow: &Escrow) {
+        let now = env.ledger().timestamp();
+        for approval in Self::get_approvals(env, &escrow.id).iter() {
+            Self::append_approval_history(
+                env,
+                &escrow.id,
+                ApprovalRecord {
+                    signer: approval.signer,
+                    release_to: approval.release_to,
+                    action: ApprovalAction::Revoked,
+                    beneficiary_amount: None,
+                    timestamp: now,
+                },
+            );
+        }
+        if let Some(proposal) = Self::get_proposal(env, &escrow.id) {
+            for signer in proposal.approvals.iter() {
+                Self::append_approval_history(
+                    env,
+                    &escrow.id,
+                    ApprovalRecord {
+                        signer,
+                        release_to: escrow.beneficiary.clone(),
+                        action: ApprovalAction::Revoked,
+                        beneficiary_amount: Some(proposal.beneficiary_amount),
+                        timestamp: now,
+                    },
+                );
+            }
+        }
+
+        Self::clear_approvals(env, &escrow.id);
+        Self::clear_approval_counts(env, escrow);
+        Self::clear_proposal(env, &escrow.id);
+    }
+
+    /// Get the total approval weight for a specific release target (O(1) lookup).
+    /// With the default unit weights this is the number of approving signers.
+    pub fn get_approval_weight_for_target(
         env: &Env,
         escrow_id: &BytesN<32>,
         release_to: &Address,
@@ -62,11 +212,30 @@ impl EscrowStorage {
         env.storage().persistent().get::<_, u32>(&key).unwrap_or(0)
     }
 
-    /// Increment the approval count for a specific release target.
-    pub fn increment_approval_count(env: &Env, escrow_id: &BytesN<32>, release_to: &Address) {
-        let count = Self::get_approval_count_for_target(env, escrow_id, release_to);
+    /// Add a signer's weight to the approvals for a specific release target.
+    pub fn add_approval_weight(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+        release_to: &Address,
+        weight: u32,
+    ) {
+        let total = Self::get_approval_weight_for_target(env, escrow_id, release_to);
+        let key = DataKey::ApprovalCount(escrow_id.clone(), release_to.clone());
+        env.storage().persistent().set(&key, &(total + weight));
+    }
+
+    /// Subtract a signer's weight from the approvals for a specific release target.
+    pub fn subtract_approval_weight(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+        release_to: &Address,
+        weight: u32,
+    ) {
+        let total = Self::get_approval_weight_for_target(env, escrow_id, release_to);
         let key = DataKey::ApprovalCount(escrow_id.clone(), release_to.clone());
-        env.storage().persistent().set(&key, &(count + 1));
+        env.storage()
+            .persistent()
+            .set(&key, &total.saturating_sub(weight));
     }
 
     /// Check if a specific signer has already approved a specific target (O(1) lookup).
@@ -94,25 +263,49 @@ impl EscrowStorage {
         env.storage().persistent().set(&key, &true);
     }
 
-    /// Clear approval counts and signer flags for given targets.
-    pub fn clear_approval_counts(
+    /// Clear the flag marking a signer as having approved a specific target.
+    pub fn remove_signer_approved(
         env: &Env,
         escrow_id: &BytesN<32>,
-        targets: &[Address],
-        signers: &[Address],
+        signer: &Address,
+        release_to: &Address,
     ) {
-        for target in targets {
-            let count_key = DataKey::ApprovalCount(escrow_id.clone(), target.clone());
+        let key = DataKey::SignerApproved(escrow_id.clone(), signer.clone(), release_to.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Clear approval weights and signer flags for both release targets.
+    pub fn clear_approval_counts(env: &Env, escrow: &Escrow) {
+        for target in [escrow.beneficiary.clone(), escrow.depositor.clone()] {
+            let count_key = DataKey::ApprovalCount(escrow.id.clone(), target.clone());
             env.storage().persistent().remove(&count_key);
-            for signer in signers {
+            for signer in escrow.signers.iter() {
                 let flag_key =
-                    DataKey::SignerApproved(escrow_id.clone(), signer.clone(), target.clone());
+                    DataKey::SignerApproved(escrow.id.clone(), signer.address, target.clone());
                 env.storage().persistent().remove(&flag_key);
             }
         }
     }
 
-    /// Get the current count of escrows created.
+    /// Get the pending split proposal for an escrow, if any.
+    pub fn get_proposal(env: &Env, escrow_id: &BytesN<32>) -> Option<ReleaseProposal> {
+        let key = DataKey::ReleaseProposal(escrow_id.clone());
+        env.storage().persistent().get::<_, ReleaseProposal>(&key)
+    }
+
+    /// Save the pending split proposal, replacing any previous one.
+    pub fn set_proposal(env: &Env, escrow_id: &BytesN<32>, proposal: &ReleaseProposal) {
+        let key = DataKey::ReleaseProposal(escrow_id.clone());
+        env.storage().persistent().set(&key, proposal);
+    }
+
+    /// Remove the pending split proposal.
+    pub fn clear_proposal(env: &Env, escrow_id: &BytesN<32>) {
+        let key = DataKey::ReleaseProposal(escrow_id.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Get the current count of live escrows (created and not cancelled).
     pub fn get_count(env: &Env) -> u32 {
         env.storage()
             .instance()
@@ -127,4 +320,353 @@ impl EscrowStorage {
             .instance()
             .set(&DataKey::EscrowCount, &(count + 1));
     }
+
+    /// Decrement escrow counter when an escrow is cancelled.
+    pub fn decrement_count(env: &Env) {
+        let count = Self::get_count(env);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowCount, &count.saturating_sub(1));
+    }
+
+    /// Remove every per-escrow key left behind by a cancelled escrow, so an escrow
+    /// re-created under the same ID starts without its predecessor's history.
+    pub fn clear_escrow_data(env: &Env, escrow: &Escrow) {
+        let id = &escrow.id;
+        Self::clear_approvals(env, id);
+        Self::clear_approval_counts(env, escrow);
+        Self::clear_proposal(env, id);
+        Self::clear_funding_schedule(env, id);
+        Self::clear_arbiter_replacement(env, id);
+        let storage = env.storage().persistent();
+        // Resetting the length hides the old records; the next escrow overwrites them
+        storage.remove(&DataKey::ApprovalHistoryLen(id.clone()));
+        storage.remove(&DataKey::DisputeInfo(id.clone()));
+        storage.remove(&DataKey::Evidence(id.clone()));
+    }
+
+    /// Get the platform admin, if the contract has been initialized.
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get::<_, Address>(&DataKey::Admin)
+    }
+
+    /// Store the platform admin.
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Check if an arbiter is in the platform-approved registry (O(1) lookup).
+    pub fn is_approved_arbiter(env: &Env, arbiter: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&DataKey::ApprovedArbiter(arbiter.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Add or remove an arbiter from the platform-approved registry.
+    pub fn set_approved_arbiter(env: &Env, arbiter: &Address, approved: bool) {
+        let key = DataKey::ApprovedArbiter(arbiter.clone());
+        if approved {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Get the annual interest rate (bps) for newly funded escrows.
+    pub fn get_interest_rate(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get::<_, u32>(&DataKey::InterestRate)
+            .unwrap_or(0)
+    }
+
+    /// Store the annual interest rate (bps).
+    pub fn set_interest_rate(env: &Env, rate_bps: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::InterestRate, &rate_bps);
+    }
+
+    /// Get the platform fee configuration, if the admin has set one.
+    pub fn get_platform_fee(env: &Env) -> Option<PlatformFee> {
+        env.storage()
+            .instance()
+            .get::<_, PlatformFee>(&DataKey::PlatformFee)
+    }
+
+    /// Store the platform fee configuration.
+    pub fn set_platform_fee(env: &Env, fee: &PlatformFee) {
+        env.storage().instance().set(&DataKey::PlatformFee, fee);
+    }
+
+    /// Get the interest reserve balance for a token.
+    pub fn get_interest_reserve(env: &Env, token: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<_, i128>(&DataKey::InterestReserve(token.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Store the interest reserve balance for a token.
+    pub fn set_interest_reserve(env: &Env, token: &Address, amount: i128) {
+        let key = DataKey::InterestReserve(token.clone());
+        env.storage().persistent().set(&key, &amount);
+    }
+
+    /// Get the agreed funding schedule for an escrow (empty if none).
+    pub fn get_funding_schedule(env: &Env, escrow_id: &BytesN<32>) -> Vec<FundingInstalment> {
+        let key = DataKey::FundingSchedule(escrow_id.clone());
+        env.storage()
+            .persistent()
+            .get::<_, Vec<FundingInstalment>>(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store the funding schedule for an escrow.
+    pub fn set_funding_schedule(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+        instalments: &Vec<FundingInstalment>,
+    ) {
+        let key = DataKey::FundingSchedule(escrow_id.clone());
+        env.storage().persistent().set(&key, instalments);
+    }
+
+    /// Remove the funding schedule for an escrow.
+    pub fn clear_funding_schedule(env: &Env, escrow_id: &BytesN<32>) {
+        let key = DataKey::FundingSchedule(escrow_id.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Get the dispute_resolution contract escrows may delegate to.
+    pub fn get_dispute_contract(env: &Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get::<_, Address>(&DataKey::DisputeContract)
+    }
+
+    /// Store the dispute_resolution contract address.
+    pub fn set_dispute_contract(env: &Env, dispute_contract: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeContract, dispute_contract);
+    }
+
+    /// Get the timeline of an escrow's dispute, if one was ever opened.
+    pub fn get_dispute_record(env: &Env, escrow_id: &BytesN<32>) -> Option<DisputeRecord> {
+        let key = DataKey::DisputeInfo(escrow_id.clone());
+        env.storage().persistent().get::<_, DisputeRecord>(&key)
+    }
+
+    /// Store the timeline of an escrow's dispute.
+    pub fn set_dispute_record(env: &Env, escrow_id: &BytesN<32>, record: &DisputeRecord) {
+        let key = DataKey::DisputeInfo(escrow_id.clone());
+        env.storage().persistent().set(&key, record);
+    }
+
+    /// Retrieve the evidence log for an escrow, oldest first.
+    pub fn get_evidence(env: &Env, escrow_id: &BytesN<32>) -> Vec<EvidenceEntry> {
+        let key = DataKey::Evidence(escrow_id.clone());
+        env.storage()
+            .persistent()
+            .get::<_, Vec<EvidenceEntry>>(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Append an entry to the evidence log.
+    pub fn add_evidence(env: &Env, escrow_id: &BytesN<32>, entry: EvidenceEntry) {
+        let mut evidence = Self::get_evidence(env, escrow_id);
+        evidence.push_back(entry);
+        let key = DataKey::Evidence(escrow_id.clone());
+        env.storage().persistent().set(&key, &evidence);
+    }
+
+    /// Get the `(response_window, resolution_window)` applied to new disputes.
+    pub fn get_dispute_windows(env: &Env) -> Option<(u64, u64)> {
+        env.storage()
+            .instance()
+            .get::<_, (u64, u64)>(&DataKey::DisputeWindows)
+    }
+
+    /// Store the `(response_window, resolution_window)` applied to new disputes.
+    pub fn set_dispute_windows(env: &Env, response_window: u64, resolution_window: u64) {
+        env.storage().instance().set(
+            &DataKey::DisputeWindows,
+            &(response_window, resolution_window),
+        );
+    }
+
+    /// Number of escrows in a party's index.
+    pub fn party_index_len(env: &Env, role: &PartyRole, party: &Address) -> u32 {
+        Self::index_len(env, &Listing::Party(role.clone(), party.clone()))
+    }
+
+    /// Escrow ID at `position` in a party's index. Entries are kept in creation order,
+    /// except that removing one moves the last entry into its place.
+    pub fn party_index_entry(
+        env: &Env,
+        role: &PartyRole,
+        party: &Address,
+        position: u32,
+    ) -> Option<BytesN<32>> {
+        Self::index_entry(env, &Listing::Party(role.clone(), party.clone()), position)
+    }
+
+    /// Number of a party's escrows currently in `status`.
+    pub fn status_index_len(
+        env: &Env,
+        role: &PartyRole,
+        party: &Address,
+        status: &EscrowStatus,
+    ) -> u32 {
+        Self::index_len(
+            env,
+            &Listing::Status(role.clone(), party.clone(), status.clone()),
+        )
+    }
+
+    /// Escrow ID at `position` among a party's escrows in `status`. Entries are kept in
+    /// the order they reached the status, except that removing one moves the last entry
+    /// into its place.
+    pub fn status_index_entry(
+        env: &Env,
+        role: &PartyRole,
+        party: &Address,
+        status: &EscrowStatus,
+        position: u32,
+    ) -> Option<BytesN<32>> {
+        Self::index_entry(
+            env,
+            &Listing::Status(role.clone(), party.clone(), status.clone()),
+            position,
+        )
+    }
+
+    fn index_len(env: &Env, listing: &Listing) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&listing.len_key())
+            .unwrap_or(0)
+    }
+
+    fn index_entry(env: &Env, listing: &Listing, position: u32) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get::<_, BytesN<32>>(&listing.entry_key(position))
+    }
+
+    fn add_to_index(env: &Env, listing: &Listing, escrow_id: &BytesN<32>) {
+        let slot_key = listing.slot_key(escrow_id);
+        if env.storage().persistent().has(&slot_key) {
+            return;
+        }
+
+        let len = Self::index_len(env, listing);
+        env.storage()
+            .persistent()
+            .set(&listing.entry_key(len), escrow_id);
+        env.storage().persistent().set(&slot_key, &len);
+        env.storage()
+            .persistent()
+            .set(&listing.len_key(), &(len + 1));
+    }
+
+    fn remove_from_index(env: &Env, listing: &Listing, escrow_id: &BytesN<32>) {
+        let slot_key = listing.slot_key(escrow_id);
+        let Some(position) = env.storage().persistent().get::<_, u32>(&slot_key) else {
+            return;
+        };
+
+        // Fill the gap with the last entry so the index stays dense
+        let last = Self::index_len(env, listing) - 1;
+        if position != last {
+            if let Some(moved) = Self::index_entry(env, listing, last) {
+                env.storage()
+                    .persistent()
+                    .set(&listing.entry_key(position), &moved);
+                env.storage()
+                    .persistent()
+                    .set(&listing.slot_key(&moved), &position);
+            }
+        }
+
+        env.storage().persistent().remove(&listing.entry_key(last));
+        env.storage().persistent().remove(&slot_key);
+        env.storage().persistent().set(&listing.len_key(), &last);
+    }
+
+    /// The depositor, beneficiary and arbiter of an escrow, with the role each plays.
+    fn parties(escrow: &Escrow) -> [(PartyRole, Address); 3] {
+        [
+            (PartyRole::Depositor, escrow.depositor.clone()),
+            (PartyRole::Beneficiary, escrow.beneficiary.clone()),
+            (PartyRole::Arbiter, escrow.arbiter.clone()),
+        ]
+    }
+
+    /// Add an escrow to its depositor, beneficiary and arbiter indexes, in full and
+    /// under its current status.
+    pub fn index_escrow(env: &Env, escrow: &Escrow) {
+        for (role, party) in Self::parties(escrow) {
+            let status = escrow.status.clone();
+            Self::add_to_index(
+                env,
+                &Listing::Party(role.clone(), party.clone()),
+                &escrow.id,
+            );
+            Self::add_to_index(env, &Listing::Status(role, party, status), &escrow.id);
+        }
+    }
+
+    /// Remove an escrow from its depositor, beneficiary and arbiter indexes.
+    pub fn unindex_escrow(env: &Env, escrow: &Escrow) {
+        for (role, party) in Self::parties(escrow) {
+            let status = escrow.status.clone();
+            Self::remove_from_index(
+                env,
+                &Listing::Party(role.clone(), party.clone()),
+                &escrow.id,
+            );
+            Self::remove_from_index(env, &Listing::Status(role, party, status), &escrow.id);
+        }
+    }
+
+    /// Move an escrow from its parties' `previous` status indexes to those of its
+    /// current status.
+    fn reindex_status(env: &Env, escrow: &Escrow, previous: &EscrowStatus) {
+        for (role, party) in Self::parties(escrow) {
+            let from = Listing::Status(role.clone(), party.clone(), previous.clone());
+            let to = Listing::Status(role, party, escrow.status.clone());
+            Self::remove_from_index(env, &from, &escrow.id);
+            Self::add_to_index(env, &to, &escrow.id);
+        }
+    }
+
+    /// Get the pending arbiter replacement for an escrow, if one was announced.
+    pub fn get_arbiter_replacement(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+    ) -> Option<ArbiterReplacement> {
+        let key = DataKey::ArbiterReplacement(escrow_id.clone());
+        env.storage()
+            .persistent()
+            .get::<_, ArbiterReplacement>(&key)
+    }
+
+    /// Store the pending arbiter replacement for an escrow.
+    pub fn set_arbiter_replacement(
+        env: &Env,
+        escrow_id: &BytesN<32>,
+        replacement: &ArbiterReplacement,
+    ) {
+        let key = DataKey::ArbiterReplacement(escrow_id.clone());
+        env.storage().persistent().set(&key, replacement);
+    }
+
+    /// Remove the pending arbiter replacement for an escrow.
+    pub fn clear_arbiter_replacement(env: &Env, escrow_id: &BytesN<32>) {
+        let key = DataKey::ArbiterReplacement(escrow_id.clone());
+        env.storage().persistent().remove(&key);
+    }
 }
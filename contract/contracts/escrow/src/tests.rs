@@ -1,12 +1,95 @@
 //! Tests for the Escrow contract.
 
-use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, BytesN, Env, Map, String, Symbol, TryFromVal, Val,
+};
 
-use crate::escrow_impl::{EscrowContract, EscrowContractClient};
-use crate::types::EscrowStatus;
+use crate::errors::EscrowError;
+use crate::escrow_impl::{EscrowContract, EscrowContractClient, MAX_PAGE_SIZE};
+use crate::types::{ApprovalAction, EscrowStatus, FundingInstalment, PanelDispute, Signer};
+
+/// Mock dispute_resolution panel that records cases and lets tests set the vote tally.
+#[contract]
+pub struct MockDisputePanel;
+
+#[contractimpl]
+impl MockDisputePanel {
+    pub fn raise_escrow_dispute(
+        env: Env,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+        agreement_id: String,
+        details_hash: String,
+    ) {
+        let case = PanelDispute {
+            agreement_id,
+            raised_by: escrow_contract,
+            details_hash,
+            raised_at: env.ledger().timestamp(),
+            resolved: false,
+            resolved_at: None,
+            votes_favor_landlord: 0,
+            votes_favor_tenant: 0,
+        };
+        env.storage().instance().set(&escrow_id, &case);
+    }
+
+    pub fn set_outcome(env: Env, escrow_id: BytesN<32>, landlord_votes: u32, tenant_votes: u32) {
+        let mut case: PanelDispute = env.storage().instance().get(&escrow_id).unwrap();
+        case.resolved = true;
+        case.resolved_at = Some(env.ledger().timestamp());
+        case.votes_favor_landlord = landlord_votes;
+        case.votes_favor_tenant = tenant_votes;
+        env.storage().instance().set(&escrow_id, &case);
+    }
+
+    /// Test hook standing in for a case some other caller raised under this escrow's key
+    pub fn set_raised_by(env: Env, escrow_id: BytesN<32>, raised_by: Address) {
+        let mut case: PanelDispute = env.storage().instance().get(&escrow_id).unwrap();
+        case.raised_by = raised_by;
+        env.storage().instance().set(&escrow_id, &case);
+    }
+
+    pub fn get_escrow_dispute(
+        env: Env,
+        _escrow_contract: Address,
+        escrow_id: BytesN<32>,
+    ) -> Option<PanelDispute> {
+        env.storage().instance().get(&escrow_id)
+    }
+}
+
+/// Mock token that keeps 1% of every transfer, like a fee-on-transfer asset.
+#[contract]
+pub struct MockFeeToken;
+
+#[contractimpl]
+impl MockFeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let fee = amount / 100;
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .instance()
+            .set(&from, &(from_balance - amount));
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .instance()
+            .set(&to, &(to_balance + amount - fee));
+    }
+}
 
 fn setup_test(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address, Address) {
     let contract_id = env.register(EscrowContract, ());
@@ -16,6 +99,11 @@ fn setup_test(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address
     let beneficiary = Address::generate(env);
     let arbiter = Address::generate(env);
 
+    let admin = Address::generate(env);
+    client
+        .mock_all_auths()
+        .initialize(&admin, &vec![env, arbiter.clone()]);
+
     let token_admin = Address::generate(env);
     let token_address = env
         .register_stellar_asset_contract_v2(token_admin)
@@ -33,7 +121,14 @@ fn test_escrow_lifecycle() {
     let amount = 1000i128;
 
     // 1. Create Escrow
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
     let escrow = client.get_escrow(&escrow_id);
     assert_eq!(escrow.status, EscrowStatus::Pending);
     assert_eq!(escrow.amount, amount);
@@ -82,7 +177,14 @@ fn test_dispute_resolution() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     let token_admin = TokenAdminClient::new(&env, &token_address);
     token_admin.mint(&depositor, &amount);
@@ -100,7 +202,7 @@ fn test_dispute_resolution() {
     client.resolve_dispute(&escrow_id, &arbiter, &depositor);
 
     let escrow = client.get_escrow(&escrow_id);
-    assert_eq!(escrow.status, EscrowStatus::Released); // resolve_dispute currently sets status to Released regardless of target
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
 
     let token_client = TokenClient::new(&env, &token_address);
     assert_eq!(token_client.balance(&depositor), amount);
@@ -113,7 +215,14 @@ fn test_unauthorized_funding() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.mock_all_auths().create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     // Try to fund from beneficiary (should fail since only depositor can fund)
     // We expect an error, but AccessControl check happens before require_auth
@@ -130,6 +239,7 @@ fn test_unique_escrow_ids() {
     struct TestContract;
 
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register(TestContract, ());
 
     let depositor = Address::generate(&env);
@@ -137,10 +247,20 @@ fn test_unique_escrow_ids() {
     let arbiter = Address::generate(&env);
     let token = Address::generate(&env);
 
+    env.as_contract(&contract_id, || {
+        EscrowContract::initialize(
+            env.clone(),
+            Address::generate(&env),
+            vec![&env, arbiter.clone()],
+        )
+    })
+    .unwrap();
+
     let escrow_id1 = env
         .as_contract(&contract_id, || {
             EscrowContract::create(
                 env.clone(),
+                String::from_str(&env, "AGR-001"),
                 depositor.clone(),
                 beneficiary.clone(),
                 arbiter.clone(),
@@ -150,12 +270,12 @@ fn test_unique_escrow_ids() {
         })
         .unwrap();
 
-    env.ledger().with_mut(|li| li.timestamp += 1);
-
+    // Identical parameters in the same ledger no longer collide
     let escrow_id2 = env
         .as_contract(&contract_id, || {
             EscrowContract::create(
                 env.clone(),
+                String::from_str(&env, "AGR-002"),
                 depositor.clone(),
                 beneficiary.clone(),
                 arbiter.clone(),
@@ -193,7 +313,14 @@ fn test_duplicate_approval_rejected() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     let token_admin = TokenAdminClient::new(&env, &token_address);
     token_admin.mint(&depositor, &amount);
@@ -219,7 +346,14 @@ fn test_approval_count_tracks_per_target() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     let token_admin = TokenAdminClient::new(&env, &token_address);
     token_admin.mint(&depositor, &amount);
@@ -244,3 +378,2208 @@ fn test_approval_count_tracks_per_target() {
     let token_client = TokenClient::new(&env, &token_address);
     assert_eq!(token_client.balance(&beneficiary), amount);
 }
+
+#[test]
+fn test_initialize_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, arbiter, _) = setup_test(&env);
+
+    let result = client.try_initialize(&Address::generate(&env), &vec![&env, arbiter]);
+    assert_eq!(result, Err(Ok(EscrowError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_create_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(&env, &contract_id);
+
+    let result = client.try_create(
+        &String::from_str(&env, "AGR-001"),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &1000,
+        &Address::generate(&env),
+    );
+    assert_eq!(result, Err(Ok(EscrowError::NotInitialized)));
+}
+
+#[test]
+fn test_create_rejects_unapproved_arbiter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, _, token_address) = setup_test(&env);
+
+    // Landlord tries to name a friend as arbiter
+    let friend = Address::generate(&env);
+    let result = client.try_create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &friend,
+        &1000,
+        &token_address,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::ArbiterNotApproved)));
+}
+
+#[test]
+fn test_admin_manages_arbiter_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    client.initialize(&admin, &vec![&env]);
+    assert!(!client.is_approved_arbiter(&arbiter));
+
+    client.add_arbiter(&admin, &arbiter);
+    assert!(client.is_approved_arbiter(&arbiter));
+
+    let outsider = Address::generate(&env);
+    let result = client.try_add_arbiter(&outsider, &outsider);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    client.remove_arbiter(&admin, &arbiter);
+    assert!(!client.is_approved_arbiter(&arbiter));
+}
+
+#[test]
+fn test_lifecycle_events_published() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    // Topics: event name + escrow_id + depositor + beneficiary
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, client.address);
+    assert_eq!(event.1.len(), 4);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, client.address);
+    assert_eq!(event.1.len(), 4);
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    // Topics: event name + escrow_id + signer
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, client.address);
+    assert_eq!(event.1.len(), 3);
+
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    let events = env.events().all();
+    let released = events
+        .iter()
+        .filter(|e| e.0 == client.address)
+        .last()
+        .unwrap();
+    assert_eq!(released.1.len(), 4);
+}
+
+#[test]
+fn test_split_release_with_deductions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // Landlord (beneficiary) claims 300 for damages, tenant gets 700 back
+    let reason = soroban_sdk::String::from_str(&env, "Carpet cleaning: 300");
+    client.propose_split(&escrow_id, &beneficiary, &300, &Some(reason.clone()));
+
+    let proposal = client.get_split_proposal(&escrow_id).unwrap();
+    assert_eq!(proposal.depositor_amount, 700);
+    assert_eq!(proposal.beneficiary_amount, 300);
+    assert_eq!(proposal.deduction_reason, Some(reason));
+    assert_eq!(proposal.approvals.len(), 1);
+
+    client.approve_split(&escrow_id, &depositor, &300);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert!(client.get_split_proposal(&escrow_id).is_none());
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 700);
+    assert_eq!(token_client.balance(&beneficiary), 300);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_split_approval_must_match_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // Approving without a proposal fails
+    let result = client.try_approve_split(&escrow_id, &depositor, &300);
+    assert_eq!(result, Err(Ok(EscrowError::ProposalMismatch)));
+
+    client.propose_split(&escrow_id, &beneficiary, &300, &None);
+
+    let result = client.try_approve_split(&escrow_id, &depositor, &200);
+    assert_eq!(result, Err(Ok(EscrowError::ProposalMismatch)));
+
+    // The proposer has already approved
+    let result = client.try_approve_split(&escrow_id, &beneficiary, &300);
+    assert_eq!(result, Err(Ok(EscrowError::AlreadySigned)));
+
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+}
+
+#[test]
+fn test_split_exceeding_amount_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let result = client.try_propose_split(&escrow_id, &beneficiary, &1001, &None);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidSplit)));
+
+    let result = client.try_propose_split(&escrow_id, &beneficiary, &-1, &None);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidSplit)));
+}
+
+#[test]
+fn test_arbiter_resolves_dispute_with_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    client.propose_split(&escrow_id, &beneficiary, &600, &None);
+
+    let reason = soroban_sdk::String::from_str(&env, "Deduction disputed");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+    // Disputing discards the pending proposal
+    assert!(client.get_split_proposal(&escrow_id).is_none());
+
+    // Only the arbiter may impose a split
+    let result = client.try_resolve_dispute_split(&escrow_id, &beneficiary, &600, &None);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    let ruling = soroban_sdk::String::from_str(&env, "Wall repaint: 250");
+    client.resolve_dispute_split(&escrow_id, &arbiter, &250, &Some(ruling));
+
+    // Watchers of dispute resolutions see the split as well
+    let resolved = env
+        .events()
+        .all()
+        .iter()
+        .filter(|e| {
+            e.0 == client.address
+                && e.1.get(0).map(|t| Symbol::try_from_val(&env, &t).ok())
+                    == Some(Some(Symbol::new(&env, "esc_resolved")))
+        })
+        .last()
+        .unwrap();
+    let data: Map<Symbol, Val> = Map::try_from_val(&env, &resolved.2).unwrap();
+    let field = |name: &str| i128::try_from_val(&env, &data.get(Symbol::new(&env, name)).unwrap());
+    assert_eq!(field("depositor_amount"), Ok(750));
+    assert_eq!(field("beneficiary_amount"), Ok(250));
+    let arbiter_field = data.get(Symbol::new(&env, "arbiter")).unwrap();
+    assert_eq!(
+        Address::try_from_val(&env, &arbiter_field),
+        Ok(arbiter.clone())
+    );
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.dispute_reason, None);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 750);
+    assert_eq!(token_client.balance(&beneficiary), 250);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_refund_to_depositor_marks_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // approve_refund and approve_release(depositor) share the same approval target
+    client.approve_refund(&escrow_id, &beneficiary);
+    assert_eq!(client.get_approval_count(&escrow_id, &depositor), 1);
+
+    let result = client.try_approve_release(&escrow_id, &beneficiary, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::AlreadySigned)));
+
+    client.approve_refund(&escrow_id, &arbiter);
+
+    // Topics: event name + escrow_id + depositor + beneficiary
+    let events = env.events().all();
+    let refunded = events
+        .iter()
+        .filter(|e| e.0 == client.address)
+        .last()
+        .unwrap();
+    assert_eq!(refunded.1.len(), 4);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), amount);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_full_split_to_depositor_marks_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    client.propose_split(&escrow_id, &depositor, &0, &None);
+    client.approve_split(&escrow_id, &beneficiary, &0);
+
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Refunded);
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), amount);
+}
+
+#[test]
+fn test_claim_refund_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // Lease ends at t=1000, landlord has 14 days to file deductions
+    let window = 14 * 24 * 60 * 60;
+    client.set_claim_window(&escrow_id, &1000, &window);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.release_date, Some(1000));
+    assert_eq!(escrow.claim_window, window);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + window - 1);
+    let result = client.try_claim_refund(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::ClaimWindowOpen)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + window);
+
+    // Only the depositor may claim
+    let result = client.try_claim_refund(&escrow_id, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    client.claim_refund(&escrow_id, &depositor);
+
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Refunded);
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), amount);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_claim_refund_requires_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let result = client.try_claim_refund(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::NoClaimWindow)));
+}
+
+#[test]
+fn test_beneficiary_deadline_for_deductions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.set_claim_window(&escrow_id, &1000, &500);
+
+    // A dispute raised inside the window blocks the unilateral claim
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    let reason = soroban_sdk::String::from_str(&env, "Broken window");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    let result = client.try_claim_refund(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::DisputeActive)));
+
+    // On a fresh escrow the beneficiary is too late once the window closes
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-002"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.set_claim_window(&escrow_id, &1000, &500);
+
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    let result = client.try_propose_split(&escrow_id, &beneficiary, &100, &None);
+    assert_eq!(result, Err(Ok(EscrowError::ClaimWindowClosed)));
+    let result = client.try_initiate_dispute(&escrow_id, &beneficiary, &reason);
+    assert_eq!(result, Err(Ok(EscrowError::ClaimWindowClosed)));
+}
+
+#[test]
+fn test_pending_deduction_blocks_claim_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.set_claim_window(&escrow_id, &1000, &500);
+
+    // Deductions filed inside the window survive its close
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    client.propose_split(&escrow_id, &beneficiary, &200, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    let result = client.try_claim_refund(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::DeductionPending)));
+
+    // Settling the deduction by agreement still works
+    client.approve_split(&escrow_id, &depositor, &200);
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 800);
+    assert_eq!(token_client.balance(&beneficiary), 200);
+}
+
+fn setup_with_admin(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let arbiter = Address::generate(env);
+    client.initialize(&admin, &vec![env, arbiter.clone()]);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    (client, admin, arbiter, token_address)
+}
+
+#[test]
+fn test_interest_accrues_and_pays_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let amount = 1000i128;
+
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &amount);
+    token_admin.mint(&platform, &100);
+    client.fund_interest_reserve(&platform, &token_address, &100);
+    assert_eq!(client.get_interest_reserve(&token_address), 100);
+
+    // 5% a year, locked in when the escrow is funded
+    client.set_interest_rate(&admin, &500);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.fund_escrow(&escrow_id, &depositor);
+    client.set_interest_rate(&admin, &0);
+    assert_eq!(client.get_escrow(&escrow_id).interest_rate_bps, 500);
+
+    env.ledger().with_mut(|li| li.timestamp += 31_536_000 / 2);
+    assert_eq!(client.get_accrued_interest(&escrow_id), 25);
+    env.ledger().with_mut(|li| li.timestamp += 31_536_000 / 2);
+    assert_eq!(client.get_accrued_interest(&escrow_id), 50);
+
+    // Released to the landlord, but the interest still belongs to the tenant
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&beneficiary), amount);
+    assert_eq!(token_client.balance(&depositor), 50);
+    assert_eq!(client.get_interest_reserve(&token_address), 50);
+    assert_eq!(client.get_accrued_interest(&escrow_id), 0);
+}
+
+#[test]
+fn test_interest_capped_at_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let amount = 1000i128;
+
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &amount);
+    token_admin.mint(&platform, &10);
+    client.fund_interest_reserve(&platform, &token_address, &10);
+
+    let result = client.try_set_interest_rate(&admin, &10_001);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidInterestRate)));
+    let result = client.try_set_interest_rate(&depositor, &500);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    client.set_interest_rate(&admin, &500);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.fund_escrow(&escrow_id, &depositor);
+
+    env.ledger().with_mut(|li| li.timestamp += 31_536_000);
+    assert_eq!(client.get_accrued_interest(&escrow_id), 50);
+
+    client.approve_refund(&escrow_id, &depositor);
+    client.approve_refund(&escrow_id, &beneficiary);
+
+    // Only what the reserve holds is paid out
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), amount + 10);
+    assert_eq!(client.get_interest_reserve(&token_address), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_escrow_bound_to_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let escrow = client.get_escrow_by_agreement(&agreement_id, &depositor, &beneficiary);
+    assert_eq!(escrow.id, escrow_id);
+    assert_eq!(escrow.agreement_id, agreement_id);
+
+    // A second escrow for the same agreement is refused rather than overwriting
+    let result = client.try_create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &500,
+        &token_address,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::EscrowAlreadyExists)));
+    assert_eq!(client.get_escrow(&escrow_id).amount, 1000);
+
+    let result = client.try_create(
+        &String::from_str(&env, ""),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &500,
+        &token_address,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::InvalidEscrowId)));
+
+    let result = client.try_get_escrow_by_agreement(
+        &String::from_str(&env, "AGR-404"),
+        &depositor,
+        &beneficiary,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::EscrowNotFound)));
+}
+
+#[test]
+fn test_third_party_cannot_claim_agreement_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let attacker = Address::generate(&env);
+    let accomplice = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+
+    // Someone else gets in first with their own parties
+    let squatted = client.create(
+        &agreement_id,
+        &attacker,
+        &accomplice,
+        &arbiter,
+        &1,
+        &token_address,
+    );
+
+    // The real parties still get their own escrow, and lookups find it
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    assert_ne!(escrow_id, squatted);
+    let escrow = client.get_escrow_by_agreement(&agreement_id, &depositor, &beneficiary);
+    assert_eq!(escrow.id, escrow_id);
+    assert_eq!(escrow.depositor, depositor);
+
+    // Naming the real parties needs their signatures
+    env.mock_auths(&[]);
+    let result = client.try_create(
+        &String::from_str(&env, "AGR-002"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_instalment_funding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 2000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+
+    client.fund_instalment(&escrow_id, &depositor, &500);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyFunded);
+    assert_eq!(escrow.funded_amount, 500);
+
+    // Cannot overpay, and release stays locked until fully funded
+    let result = client.try_fund_instalment(&escrow_id, &depositor, &1501);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidFundingAmount)));
+    let result = client.try_approve_release(&escrow_id, &depositor, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+
+    client.fund_instalment(&escrow_id, &depositor, &1000);
+    assert_eq!(client.get_escrow(&escrow_id).funded_amount, 1500);
+
+    // fund_escrow deposits whatever is left
+    client.fund_escrow(&escrow_id, &depositor);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Funded);
+    assert_eq!(escrow.funded_amount, amount);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), amount);
+}
+
+#[test]
+fn test_funding_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 2000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+
+    // Instalments must add up to the amount
+    let short = vec![
+        &env,
+        FundingInstalment {
+            due_date: 100,
+            amount: 1000,
+        },
+    ];
+    let result = client.try_set_funding_schedule(&escrow_id, &short);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidSchedule)));
+
+    let schedule = vec![
+        &env,
+        FundingInstalment {
+            due_date: 100,
+            amount: 1000,
+        },
+        FundingInstalment {
+            due_date: 200,
+            amount: 1000,
+        },
+    ];
+    client.set_funding_schedule(&escrow_id, &schedule);
+    assert_eq!(client.get_funding_schedule(&escrow_id), schedule);
+
+    assert_eq!(client.get_funding_due(&escrow_id), 0);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.get_funding_due(&escrow_id), 1000);
+
+    client.fund_instalment(&escrow_id, &depositor, &1000);
+    assert_eq!(client.get_funding_due(&escrow_id), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 250);
+    assert_eq!(client.get_funding_due(&escrow_id), 1000);
+}
+
+#[test]
+fn test_top_up_after_amendment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1500);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.propose_split(&escrow_id, &beneficiary, &300, &None);
+
+    let result = client.try_increase_amount(&escrow_id, &amount);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidFundingAmount)));
+
+    client.increase_amount(&escrow_id, &1500);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyFunded);
+    assert_eq!(escrow.amount, 1500);
+    assert_eq!(escrow.funded_amount, amount);
+    // Approvals given for the old amount are dropped, and the history says so
+    assert_eq!(client.get_approval_count(&escrow_id, &beneficiary), 0);
+    assert!(client.get_split_proposal(&escrow_id).is_none());
+    let history = client.get_approval_history(&escrow_id, &2, &MAX_PAGE_SIZE);
+    assert_eq!(history.len(), 2);
+    let release = history.get(0).unwrap();
+    assert_eq!(release.action, ApprovalAction::Revoked);
+    assert_eq!(release.signer, depositor);
+    assert_eq!(release.beneficiary_amount, None);
+    let split = history.get(1).unwrap();
+    assert_eq!(split.action, ApprovalAction::Revoked);
+    assert_eq!(split.signer, beneficiary);
+    assert_eq!(split.beneficiary_amount, Some(300));
+
+    client.fund_instalment(&escrow_id, &depositor, &500);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&beneficiary), 1500);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_weighted_approval_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let co_tenant = Address::generate(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    // Co-tenants each weigh 1, the landlord 2; release needs weight 3
+    let signers = vec![
+        &env,
+        Signer {
+            address: depositor.clone(),
+            weight: 1,
+        },
+        Signer {
+            address: co_tenant.clone(),
+            weight: 1,
+        },
+        Signer {
+            address: beneficiary.clone(),
+            weight: 2,
+        },
+    ];
+    client.set_approval_policy(&escrow_id, &signers, &3);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.signers, signers);
+    assert_eq!(escrow.threshold, 3);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // The arbiter is no longer a signer
+    let result = client.try_approve_release(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidSigner)));
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &co_tenant, &beneficiary);
+    assert_eq!(client.get_approval_count(&escrow_id, &beneficiary), 2);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+
+    client.approve_release(&escrow_id, &beneficiary, &beneficiary);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&beneficiary), amount);
+}
+
+#[test]
+fn test_invalid_approval_policy_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let signers = vec![
+        &env,
+        Signer {
+            address: depositor.clone(),
+            weight: 1,
+        },
+        Signer {
+            address: beneficiary.clone(),
+            weight: 1,
+        },
+    ];
+    // Unreachable threshold
+    let result = client.try_set_approval_policy(&escrow_id, &signers, &3);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidPolicy)));
+    let result = client.try_set_approval_policy(&escrow_id, &signers, &0);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidPolicy)));
+
+    let duplicated = vec![
+        &env,
+        Signer {
+            address: depositor.clone(),
+            weight: 1,
+        },
+        Signer {
+            address: depositor.clone(),
+            weight: 1,
+        },
+    ];
+    let result = client.try_set_approval_policy(&escrow_id, &duplicated, &2);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidPolicy)));
+
+    // The policy is fixed once funds are deposited
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    client.fund_escrow(&escrow_id, &depositor);
+    let result = client.try_set_approval_policy(&escrow_id, &signers, &2);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+}
+
+#[test]
+fn test_revoke_approval_and_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // Nothing to revoke yet
+    let result = client.try_revoke_approval(&escrow_id, &depositor, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::ApprovalNotFound)));
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.revoke_approval(&escrow_id, &depositor, &beneficiary);
+    assert_eq!(client.get_approval_count(&escrow_id, &beneficiary), 0);
+
+    // The arbiter's approval alone no longer releases the funds
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+
+    // The depositor can approve again after revoking
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+
+    // History survives the release, revocation included
+    let history = client.get_approval_history(&escrow_id, &0, &MAX_PAGE_SIZE);
+    assert_eq!(history.len(), 4);
+    let actions: [ApprovalAction; 4] = [
+        ApprovalAction::Approved,
+        ApprovalAction::Revoked,
+        ApprovalAction::Approved,
+        ApprovalAction::Approved,
+    ];
+    let signers = [&depositor, &depositor, &arbiter, &depositor];
+    for (i, record) in history.iter().enumerate() {
+        assert_eq!(record.action, actions[i]);
+        assert_eq!(record.signer, *signers[i]);
+        assert_eq!(record.release_to, beneficiary);
+    }
+}
+
+#[test]
+fn test_long_approval_history_does_not_block_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let cycles = 150u32;
+    for _ in 0..cycles {
+        client.approve_release(&escrow_id, &depositor, &beneficiary);
+        client.revoke_approval(&escrow_id, &depositor, &beneficiary);
+    }
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+
+    // The history pages through every record, the release approvals last
+    let total = cycles * 2 + 2;
+    assert_eq!(client.get_approval_history_len(&escrow_id), total);
+    let first = client.get_approval_history(&escrow_id, &0, &1000);
+    assert_eq!(first.len(), MAX_PAGE_SIZE);
+    let last = client.get_approval_history(&escrow_id, &(total - 2), &MAX_PAGE_SIZE);
+    assert_eq!(last.len(), 2);
+    assert_eq!(last.get(0).unwrap().signer, depositor);
+    assert_eq!(last.get(1).unwrap().signer, arbiter);
+    assert_eq!(last.get(1).unwrap().action, ApprovalAction::Approved);
+}
+
+#[test]
+fn test_split_approvals_recorded_and_revocable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // Nothing to revoke yet
+    let result = client.try_revoke_split_approval(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::ApprovalNotFound)));
+
+    client.propose_split(&escrow_id, &beneficiary, &300, &None);
+    client.revoke_split_approval(&escrow_id, &beneficiary);
+    assert!(client
+        .get_split_proposal(&escrow_id)
+        .unwrap()
+        .approvals
+        .is_empty());
+
+    // A new proposal withdraws the approvals the old one had collected
+    client.approve_split(&escrow_id, &arbiter, &300);
+    client.propose_split(&escrow_id, &depositor, &100, &None);
+    client.approve_split(&escrow_id, &arbiter, &100);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+
+    let history = client.get_approval_history(&escrow_id, &0, &MAX_PAGE_SIZE);
+    let expected = [
+        (&beneficiary, ApprovalAction::Approved, 300),
+        (&beneficiary, ApprovalAction::Revoked, 300),
+        (&arbiter, ApprovalAction::Approved, 300),
+        (&arbiter, ApprovalAction::Revoked, 300),
+        (&depositor, ApprovalAction::Approved, 100),
+        (&arbiter, ApprovalAction::Approved, 100),
+    ];
+    assert_eq!(history.len(), expected.len() as u32);
+    for (i, record) in history.iter().enumerate() {
+        let (signer, action, split) = &expected[i];
+        assert_eq!(record.signer, **signer);
+        assert_eq!(record.action, *action);
+        assert_eq!(record.release_to, beneficiary);
+        assert_eq!(record.beneficiary_amount, Some(*split));
+    }
+}
+
+#[test]
+fn test_dispute_delegated_to_arbiter_panel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+    let amount = 1000i128;
+
+    let panel_id = env.register(MockDisputePanel, ());
+    let panel = MockDisputePanelClient::new(&env, &panel_id);
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    // Delegation needs a configured panel
+    let result = client.try_delegate_disputes(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::DisputeContractNotSet)));
+
+    client.set_dispute_contract(&admin, &panel_id);
+    client.delegate_disputes(&escrow_id);
+    assert_eq!(
+        client.get_escrow(&escrow_id).dispute_contract,
+        Some(panel_id.clone())
+    );
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let reason = String::from_str(&env, "QmEvidenceHash");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+    let case = panel
+        .get_escrow_dispute(&client.address, &escrow_id)
+        .unwrap();
+    assert_eq!(case.details_hash, reason);
+
+    // The single arbiter can no longer decide, and nothing moves before the panel rules
+    let result = client.try_resolve_dispute(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::DisputeDelegated)));
+    let result = client.try_settle_panel_dispute(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::PanelNotResolved)));
+
+    panel.set_outcome(&escrow_id, &1, &2);
+    client.settle_panel_dispute(&escrow_id);
+
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Refunded);
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), amount);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_panel_case_must_belong_to_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+    let amount = 1000i128;
+
+    let panel_id = env.register(MockDisputePanel, ());
+    let panel = MockDisputePanelClient::new(&env, &panel_id);
+    client.set_dispute_contract(&admin, &panel_id);
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.delegate_disputes(&escrow_id);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let reason = String::from_str(&env, "Damage");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+    let case = panel
+        .get_escrow_dispute(&client.address, &escrow_id)
+        .unwrap();
+    assert_eq!(case.raised_by, client.address);
+
+    // A resolved case raised by someone else cannot settle this escrow
+    panel.set_outcome(&escrow_id, &3, &0);
+    panel.set_raised_by(&escrow_id, &Address::generate(&env));
+    let result = client.try_settle_panel_dispute(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::PanelCaseMismatch)));
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Disputed);
+}
+
+#[test]
+fn test_failed_panel_call_maps_to_escrow_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+    let amount = 1000i128;
+
+    // A token contract has no panel functions, so every panel call fails
+    let not_a_panel = token_address.clone();
+    client.set_dispute_contract(&admin, &not_a_panel);
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.delegate_disputes(&escrow_id);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let reason = String::from_str(&env, "Damage");
+    let result = client.try_initiate_dispute(&escrow_id, &beneficiary, &reason);
+    assert_eq!(result, Err(Ok(EscrowError::PanelCallFailed)));
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+}
+
+#[test]
+fn test_evidence_submitted_within_response_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let amount = 1000i128;
+
+    let result = client.try_set_dispute_windows(&admin, &200, &100);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidDisputeWindows)));
+    client.set_dispute_windows(&admin, &100, &500);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // No evidence before a dispute is open
+    let hash = String::from_str(&env, "QmPhotos");
+    let result = client.try_submit_evidence(&escrow_id, &depositor, &hash);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.initiate_dispute(&escrow_id, &depositor, &String::from_str(&env, "Damage"));
+
+    let record = client.get_dispute_record(&escrow_id).unwrap();
+    assert_eq!(record.initiated_by, depositor);
+    assert_eq!(record.opened_at, 1_000);
+    assert_eq!(record.response_deadline, 1_100);
+    assert_eq!(record.resolution_deadline, 1_500);
+
+    client.submit_evidence(&escrow_id, &depositor, &hash);
+    env.ledger().with_mut(|li| li.timestamp = 1_050);
+    let reply = String::from_str(&env, "QmInventory");
+    client.submit_evidence(&escrow_id, &beneficiary, &reply);
+
+    let result = client.try_submit_evidence(&escrow_id, &arbiter, &reply);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+    let result = client.try_submit_evidence(&escrow_id, &beneficiary, &String::from_str(&env, ""));
+    assert_eq!(result, Err(Ok(EscrowError::EmptyEvidence)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_101);
+    let result = client.try_submit_evidence(&escrow_id, &beneficiary, &reply);
+    assert_eq!(result, Err(Ok(EscrowError::ResponseWindowClosed)));
+
+    // The log survives settlement
+    client.resolve_dispute(&escrow_id, &arbiter, &beneficiary);
+    let evidence = client.get_evidence(&escrow_id);
+    assert_eq!(evidence.len(), 2);
+    let first = evidence.get(0).unwrap();
+    assert_eq!(first.submitted_by, depositor);
+    assert_eq!(first.evidence_hash, hash);
+    assert_eq!(first.submitted_at, 1_000);
+    let second = evidence.get(1).unwrap();
+    assert_eq!(second.submitted_by, beneficiary);
+    assert_eq!(second.submitted_at, 1_050);
+}
+
+#[test]
+fn test_unresolved_dispute_falls_back_to_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let amount = 1000i128;
+    client.set_dispute_windows(&admin, &100, &500);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.initiate_dispute(&escrow_id, &beneficiary, &String::from_str(&env, "Unpaid"));
+
+    let result = client.try_escalate_dispute(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::ResolutionDeadlineNotReached)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_501);
+    let result = client.try_resolve_dispute(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::ResolutionDeadlinePassed)));
+
+    client.escalate_dispute(&escrow_id);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Refunded);
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), amount);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_unresolved_dispute_escalates_to_panel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+    let amount = 1000i128;
+
+    let panel_id = env.register(MockDisputePanel, ());
+    let panel = MockDisputePanelClient::new(&env, &panel_id);
+    client.set_dispute_contract(&admin, &panel_id);
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let reason = String::from_str(&env, "Damage");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+    assert!(panel
+        .get_escrow_dispute(&client.address, &escrow_id)
+        .is_none());
+
+    env.ledger().with_mut(|li| li.timestamp += 2_592_001);
+    client.escalate_dispute(&escrow_id);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Disputed);
+    assert_eq!(escrow.dispute_contract, Some(panel_id.clone()));
+    assert_eq!(
+        panel
+            .get_escrow_dispute(&client.address, &escrow_id)
+            .unwrap()
+            .details_hash,
+        reason
+    );
+
+    panel.set_outcome(&escrow_id, &3, &0);
+    client.settle_panel_dispute(&escrow_id);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&beneficiary),
+        amount
+    );
+}
+
+#[test]
+fn test_stalled_panel_falls_back_to_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let amount = 1000i128;
+
+    let panel_id = env.register(MockDisputePanel, ());
+    client.set_dispute_contract(&admin, &panel_id);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.initiate_dispute(&escrow_id, &beneficiary, &String::from_str(&env, "Damage"));
+
+    // The arbiter misses the deadline, so the case goes to the panel with a new one
+    env.ledger().with_mut(|li| li.timestamp += 2_592_001);
+    client.escalate_dispute(&escrow_id);
+    let escalated_at = env.ledger().timestamp();
+    assert_eq!(
+        client
+            .get_dispute_record(&escrow_id)
+            .unwrap()
+            .resolution_deadline,
+        escalated_at + 2_592_000
+    );
+    let result = client.try_escalate_dispute(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::ResolutionDeadlineNotReached)));
+
+    // The panel never reaches a decision: the depositor gets the deposit back
+    env.ledger().with_mut(|li| li.timestamp += 2_592_001);
+    client.escalate_dispute(&escrow_id);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Refunded);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&depositor),
+        amount
+    );
+}
+
+#[test]
+fn test_cancel_pending_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    assert_eq!(client.get_escrow_count(), 1);
+
+    let result = client.try_cancel(&escrow_id, &arbiter);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    client.cancel(&escrow_id, &beneficiary);
+    assert_eq!(
+        client.get_escrow(&escrow_id).status,
+        EscrowStatus::Cancelled
+    );
+    assert_eq!(client.get_escrow_count(), 0);
+
+    let result = client.try_fund_escrow(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+
+    // The agreement can be given a fresh escrow
+    let again = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    assert_eq!(again, escrow_id);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Pending);
+    assert_eq!(client.get_escrow_count(), 1);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    let result = client.try_cancel(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+}
+
+#[test]
+fn test_expire_partially_funded_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+    let token_client = TokenClient::new(&env, &token_address);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    let result = client.try_expire(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::FundingDeadlineNotReached)));
+    let result = client.try_set_funding_deadline(&escrow_id, &1_000);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidFundingDeadline)));
+    client.set_funding_deadline(&escrow_id, &2_000);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_instalment(&escrow_id, &depositor, &400);
+
+    let result = client.try_expire(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::FundingDeadlineNotReached)));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    client.expire(&escrow_id);
+
+    // Topics: event name + escrow_id + depositor + beneficiary
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, client.address);
+    assert_eq!(event.1.len(), 4);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+    assert_eq!(token_client.balance(&depositor), amount);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_escrow_count(), 0);
+}
+
+#[test]
+fn test_increase_after_deadline_does_not_expire_funded_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+    let token_client = TokenClient::new(&env, &token_address);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.set_funding_deadline(&escrow_id, &2_000);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    // The amendment lands after the deadline the deposit already met
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    client.increase_amount(&escrow_id, &1500);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyFunded);
+    assert_eq!(escrow.funding_deadline, None);
+
+    let result = client.try_expire(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::FundingDeadlineNotReached)));
+    assert_eq!(token_client.balance(&client.address), amount);
+
+    // A newly agreed deadline can still be enforced
+    client.set_funding_deadline(&escrow_id, &4_000);
+    env.ledger().with_mut(|li| li.timestamp = 4_001);
+    client.expire(&escrow_id);
+    assert_eq!(token_client.balance(&depositor), amount);
+}
+
+#[test]
+fn test_party_listings_with_filters_and_paging() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let other_tenant = Address::generate(&env);
+    let amount = 1000i128;
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &(amount * 2));
+
+    let funded = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.fund_escrow(&funded, &depositor);
+
+    let disputed = client.create(
+        &String::from_str(&env, "AGR-002"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.fund_escrow(&disputed, &depositor);
+    client.initiate_dispute(&disputed, &beneficiary, &String::from_str(&env, "Damage"));
+
+    let cancelled = client.create(
+        &String::from_str(&env, "AGR-003"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    client.cancel(&cancelled, &depositor);
+
+    let elsewhere = client.create(
+        &String::from_str(&env, "AGR-004"),
+        &other_tenant,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    let all = vec![&env];
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor, &all, &0, &10),
+        vec![&env, funded.clone(), disputed.clone(), cancelled.clone()]
+    );
+    assert_eq!(
+        client.get_escrows_by_beneficiary(&beneficiary, &all, &0, &10),
+        vec![
+            &env,
+            funded.clone(),
+            disputed.clone(),
+            cancelled.clone(),
+            elsewhere.clone()
+        ]
+    );
+
+    // Deposits the tenant currently has held
+    let held = vec![
+        &env,
+        EscrowStatus::PartiallyFunded,
+        EscrowStatus::Funded,
+        EscrowStatus::Disputed,
+    ];
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor, &held, &0, &10),
+        vec![&env, funded.clone(), disputed.clone()]
+    );
+
+    // Arbiter work queue
+    let queue = vec![&env, EscrowStatus::Disputed];
+    assert_eq!(
+        client.get_escrows_by_arbiter(&arbiter, &queue, &0, &10),
+        vec![&env, disputed.clone()]
+    );
+
+    // Paging counts matching escrows only
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor, &held, &1, &1),
+        vec![&env, disputed.clone()]
+    );
+    assert_eq!(
+        client.get_escrows_by_beneficiary(&beneficiary, &all, &2, &2),
+        vec![&env, cancelled.clone(), elsewhere.clone()]
+    );
+    assert!(client
+        .get_escrows_by_depositor(&depositor, &all, &3, &10)
+        .is_empty());
+
+    // Filtered results follow the order of the statuses asked for
+    let disputed_first = vec![&env, EscrowStatus::Disputed, EscrowStatus::Funded];
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor, &disputed_first, &0, &10),
+        vec![&env, disputed.clone(), funded.clone()]
+    );
+
+    // Settling the dispute moves the escrow out of the arbiter's queue
+    client.resolve_dispute(&disputed, &arbiter, &beneficiary);
+    assert!(client
+        .get_escrows_by_arbiter(&arbiter, &queue, &0, &10)
+        .is_empty());
+    assert_eq!(
+        client.get_escrows_by_beneficiary(
+            &beneficiary,
+            &vec![&env, EscrowStatus::Released],
+            &0,
+            &10
+        ),
+        vec![&env, disputed]
+    );
+}
+
+#[test]
+fn test_recreated_escrow_moves_between_party_indexes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let new_arbiter = Address::generate(&env);
+    client.add_arbiter(&admin, &new_arbiter);
+    let agreement_id = String::from_str(&env, "AGR-001");
+    let all = vec![&env];
+
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.cancel(&escrow_id, &depositor);
+    let again = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &new_arbiter,
+        &1000,
+        &token_address,
+    );
+    assert_eq!(again, escrow_id);
+
+    assert!(client
+        .get_escrows_by_arbiter(&arbiter, &all, &0, &10)
+        .is_empty());
+    assert_eq!(
+        client.get_escrows_by_arbiter(&new_arbiter, &all, &0, &10),
+        vec![&env, escrow_id.clone()]
+    );
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor, &all, &0, &10),
+        vec![&env, escrow_id]
+    );
+}
+
+#[test]
+fn test_recreated_escrow_starts_without_stale_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let agreement_id = String::from_str(&env, "AGR-001");
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let escrow_id = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.increase_amount(&escrow_id, &2000);
+    let schedule = vec![
+        &env,
+        FundingInstalment {
+            due_date: 1_500,
+            amount: 2000,
+        },
+    ];
+    client.set_funding_schedule(&escrow_id, &schedule);
+    client.set_funding_deadline(&escrow_id, &2_000);
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    client.expire(&escrow_id);
+    assert!(!client
+        .get_approval_history(&escrow_id, &0, &MAX_PAGE_SIZE)
+        .is_empty());
+
+    let again = client.create(
+        &agreement_id,
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    assert_eq!(again, escrow_id);
+    assert!(client
+        .get_approval_history(&escrow_id, &0, &MAX_PAGE_SIZE)
+        .is_empty());
+    assert!(client.get_funding_schedule(&escrow_id).is_empty());
+    assert!(client.get_evidence(&escrow_id).is_empty());
+    assert_eq!(client.get_dispute_record(&escrow_id), None);
+}
+
+#[test]
+fn test_party_index_stays_dense_after_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let new_arbiter = Address::generate(&env);
+    client.add_arbiter(&admin, &new_arbiter);
+    let all = vec![&env];
+
+    let mut ids = vec![&env];
+    for agreement in ["AGR-001", "AGR-002", "AGR-003"] {
+        ids.push_back(client.create(
+            &String::from_str(&env, agreement),
+            &depositor,
+            &beneficiary,
+            &arbiter,
+            &1000,
+            &token_address,
+        ));
+    }
+
+    // Move the first escrow to another arbiter: the last entry fills its slot
+    client.cancel(&ids.get(0).unwrap(), &depositor);
+    client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &new_arbiter,
+        &1000,
+        &token_address,
+    );
+
+    assert_eq!(
+        client.get_escrows_by_arbiter(&arbiter, &all, &0, &10),
+        vec![&env, ids.get(2).unwrap(), ids.get(1).unwrap()]
+    );
+    assert_eq!(
+        client.get_escrows_by_arbiter(&arbiter, &all, &1, &1),
+        vec![&env, ids.get(1).unwrap()]
+    );
+    // Re-created with the same depositor, it is listed once for them
+    assert_eq!(
+        client
+            .get_escrows_by_depositor(&depositor, &all, &0, &10)
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn test_fee_on_transfer_token_credits_what_arrived() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, _) = setup_test(&env);
+    let token_id = env.register(MockFeeToken, ());
+    let token = MockFeeTokenClient::new(&env, &token_id);
+    token.mint(&depositor, &2000);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_id,
+    );
+
+    // 1% of the transfer never arrives, so the escrow is still short
+    client.fund_escrow(&escrow_id, &depositor);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyFunded);
+    assert_eq!(escrow.funded_amount, 990);
+    assert_eq!(token.balance(&client.address), 990);
+
+    client.fund_escrow(&escrow_id, &depositor);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Funded);
+    assert_eq!(escrow.funded_amount, 1000);
+    assert_eq!(token.balance(&client.address), 1000);
+}
+
+#[test]
+fn test_platform_fee_taken_on_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token_client = TokenClient::new(&env, &token_address);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &3000);
+
+    let result = client.try_set_platform_fee(&admin, &1_001, &treasury);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidPlatformFee)));
+
+    // Escrows created before the fee is configured keep a zero fee
+    let before_fee = client.create(
+        &String::from_str(&env, "AGR-000"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    client.set_platform_fee(&admin, &250, &treasury);
+    assert_eq!(client.get_platform_fee().unwrap().fee_bps, 250);
+
+    let released = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    let refunded = client.create(
+        &String::from_str(&env, "AGR-002"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    assert_eq!(client.get_escrow(&released).fee_bps, 250);
+
+    for id in [&before_fee, &released, &refunded] {
+        client.fund_escrow(id, &depositor);
+    }
+
+    client.approve_release(&released, &depositor, &beneficiary);
+    client.approve_release(&released, &arbiter, &beneficiary);
+    assert_eq!(token_client.balance(&beneficiary), 975);
+    assert_eq!(token_client.balance(&treasury), 25);
+
+    // Refunds to the depositor are not charged
+    client.approve_release(&refunded, &beneficiary, &depositor);
+    client.approve_release(&refunded, &arbiter, &depositor);
+    assert_eq!(token_client.balance(&depositor), 1000);
+
+    client.approve_release(&before_fee, &depositor, &beneficiary);
+    client.approve_release(&before_fee, &arbiter, &beneficiary);
+    assert_eq!(token_client.balance(&beneficiary), 1975);
+    assert_eq!(token_client.balance(&treasury), 25);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_multi_asset_escrow_funds_and_splits_every_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, usdc) = setup_test(&env);
+    let xlm = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let usdc_client = TokenClient::new(&env, &usdc);
+    let xlm_client = TokenClient::new(&env, &xlm);
+    TokenAdminClient::new(&env, &usdc).mint(&depositor, &1000);
+    TokenAdminClient::new(&env, &xlm).mint(&depositor, &5000);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &usdc,
+    );
+
+    let result = client.try_add_asset_leg(&escrow_id, &usdc, &500);
+    assert_eq!(result, Err(Ok(EscrowError::DuplicateAssetLeg)));
+    client.add_asset_leg(&escrow_id, &xlm, &5000);
+
+    // The primary token alone does not fund the escrow
+    client.fund_instalment(&escrow_id, &depositor, &1000);
+    assert_eq!(
+        client.get_escrow(&escrow_id).status,
+        EscrowStatus::PartiallyFunded
+    );
+
+    let result = client.try_fund_asset_leg(&escrow_id, &depositor, &xlm, &5001);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidFundingAmount)));
+    client.fund_asset_leg(&escrow_id, &depositor, &xlm, &2000);
+    client.fund_escrow(&escrow_id, &depositor);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+
+    let balances = client.get_escrow_balances(&escrow_id);
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances.get(0).unwrap().token, usdc);
+    assert_eq!(balances.get(0).unwrap().funded_amount, 1000);
+    assert_eq!(balances.get(1).unwrap().token, xlm);
+    assert_eq!(balances.get(1).unwrap().funded_amount, 5000);
+
+    // 30% to the beneficiary in each token
+    client.propose_split(
+        &escrow_id,
+        &depositor,
+        &300,
+        &Some(String::from_str(&env, "Cleaning")),
+    );
+    client.approve_split(&escrow_id, &beneficiary, &300);
+
+    assert_eq!(usdc_client.balance(&beneficiary), 300);
+    assert_eq!(usdc_client.balance(&depositor), 700);
+    assert_eq!(xlm_client.balance(&beneficiary), 1500);
+    assert_eq!(xlm_client.balance(&depositor), 3500);
+    assert_eq!(usdc_client.balance(&client.address), 0);
+    assert_eq!(xlm_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_multi_asset_expiry_refunds_every_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, usdc) = setup_test(&env);
+    let xlm = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    TokenAdminClient::new(&env, &xlm).mint(&depositor, &5000);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &usdc,
+    );
+    client.add_asset_leg(&escrow_id, &xlm, &5000);
+    client.set_funding_deadline(&escrow_id, &100);
+    client.fund_asset_leg(&escrow_id, &depositor, &xlm, &5000);
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    client.expire(&escrow_id);
+
+    assert_eq!(
+        client.get_escrow(&escrow_id).status,
+        EscrowStatus::Cancelled
+    );
+    let xlm_client = TokenClient::new(&env, &xlm);
+    assert_eq!(xlm_client.balance(&depositor), 5000);
+    assert_eq!(xlm_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_admin_replaces_lost_arbiter_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let new_arbiter = Address::generate(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+
+    // Funds can still move without the arbiter, so it cannot be replaced yet
+    let result = client.try_propose_arbiter_replacement(&admin, &escrow_id, &new_arbiter);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+
+    client.initiate_dispute(&escrow_id, &beneficiary, &String::from_str(&env, "Damage"));
+
+    // The dispute withdraws the pending approval and says so in the history
+    let history = client.get_approval_history(&escrow_id, &0, &MAX_PAGE_SIZE);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(1).unwrap().action, ApprovalAction::Revoked);
+    assert_eq!(history.get(1).unwrap().signer, depositor);
+    assert_eq!(client.get_approval_count(&escrow_id, &beneficiary), 0);
+
+    // Only approved, uninvolved arbiters can take over
+    let result = client.try_propose_arbiter_replacement(&admin, &escrow_id, &new_arbiter);
+    assert_eq!(result, Err(Ok(EscrowError::ArbiterNotApproved)));
+    client.add_arbiter(&admin, &new_arbiter);
+    let result = client.try_propose_arbiter_replacement(&admin, &escrow_id, &arbiter);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidSigner)));
+
+    let result = client.try_execute_arbiter_replacement(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::ReplacementNotFound)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.propose_arbiter_replacement(&admin, &escrow_id, &new_arbiter);
+    // Topics: event name + escrow_id + old_arbiter
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, client.address);
+    assert_eq!(event.1.len(), 3);
+
+    let pending = client.get_arbiter_replacement(&escrow_id).unwrap();
+    assert_eq!(pending.executable_at, 1_000 + 604_800);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 604_799);
+    let result = client.try_execute_arbiter_replacement(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::ReplacementTimelocked)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 604_800);
+    client.execute_arbiter_replacement(&escrow_id);
+    assert!(client.get_arbiter_replacement(&escrow_id).is_none());
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.arbiter, new_arbiter);
+    assert!(escrow.signers.iter().any(|s| s.address == new_arbiter));
+    assert!(!escrow.signers.iter().any(|s| s.address == arbiter));
+
+    let queue = vec![&env, EscrowStatus::Disputed];
+    assert!(client
+        .get_escrows_by_arbiter(&arbiter, &queue, &0, &10)
+        .is_empty());
+    assert_eq!(
+        client.get_escrows_by_arbiter(&new_arbiter, &queue, &0, &10),
+        vec![&env, escrow_id.clone()]
+    );
+
+    // Only the new arbiter can resolve, and still only in favour of a party
+    assert!(client
+        .try_resolve_dispute(&escrow_id, &arbiter, &beneficiary)
+        .is_err());
+    assert!(client
+        .try_resolve_dispute(&escrow_id, &new_arbiter, &admin)
+        .is_err());
+    client.resolve_dispute(&escrow_id, &new_arbiter, &beneficiary);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&beneficiary),
+        amount
+    );
+}
+
+#[test]
+fn test_cancel_arbiter_replacement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, arbiter, token_address) = setup_with_admin(&env);
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let new_arbiter = Address::generate(&env);
+    client.add_arbiter(&admin, &new_arbiter);
+
+    let escrow_id = client.create(
+        &String::from_str(&env, "AGR-001"),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.initiate_dispute(
+        &escrow_id,
+        &depositor,
+        &String::from_str(&env, "No repairs"),
+    );
+
+    let result = client.try_cancel_arbiter_replacement(&admin, &escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::ReplacementNotFound)));
+
+    client.propose_arbiter_replacement(&admin, &escrow_id, &new_arbiter);
+    client.cancel_arbiter_replacement(&admin, &escrow_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 604_800);
+    let result = client.try_execute_arbiter_replacement(&escrow_id);
+    assert_eq!(result, Err(Ok(EscrowError::ReplacementNotFound)));
+    assert_eq!(client.get_escrow(&escrow_id).arbiter, arbiter);
+}
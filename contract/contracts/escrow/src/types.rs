@@ -1,5 +1,5 @@
 //! Data structures and enums for the Escrow contract.
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// Status of an escrow throughout its lifecycle.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -15,22 +15,35 @@ pub enum EscrowStatus {
     Refunded = 3,
     /// Under dispute, awaiting admin resolution
     Disputed = 4,
+    /// Some, but not all, of the amount has been deposited
+    PartiallyFunded = 5,
+    /// Cancelled or expired before it was fully funded
+    Cancelled = 6,
 }
 
-/// Represents a security deposit escrow managed by 2-of-3 multi-sig.
+/// Represents a security deposit escrow managed by a weighted M-of-N multi-sig
+/// (2-of-3 across depositor, beneficiary and arbiter by default).
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
 pub struct Escrow {
     /// Unique identifier for the escrow (hash of agreement_id)
     pub id: BytesN<32>,
+    /// Rental agreement this escrow holds the deposit for
+    pub agreement_id: String,
     /// The party depositing funds (tenant)
     pub depositor: Address,
     /// The party who benefits from the deposit (landlord)
     pub beneficiary: Address,
     /// The admin/arbiter who can resolve disputes
     pub arbiter: Address,
-    /// Amount of funds in the escrow
+    /// Parties whose approvals count towards a release, with their weights
+    pub signers: Vec<Signer>,
+    /// Total approval weight needed to execute a release
+    pub threshold: u32,
+    /// Amount of funds the escrow must hold to be fully funded
     pub amount: i128,
+    /// Amount deposited so far
+    pub funded_amount: i128,
     /// Token contract address (USDC, XLM, etc.)
     pub token: Address,
     /// Current status of the escrow
@@ -39,6 +52,122 @@ pub struct Escrow {
     pub created_at: u64,
     /// Reason for dispute, if any
     pub dispute_reason: Option<String>,
+    /// Date the deposit becomes due back (e.g. lease end), if a claim window is set
+    pub release_date: Option<u64>,
+    /// Seconds after `release_date` during which deductions or disputes may be raised
+    pub claim_window: u64,
+    /// Timestamp of the last deposit or amount change; interest accrues from here
+    pub funded_at: Option<u64>,
+    /// Annual interest rate in basis points, locked in at the first deposit
+    pub interest_rate_bps: u32,
+    /// Interest accrued on earlier balances, up to `funded_at`
+    pub interest_accrued: i128,
+    /// dispute_resolution contract that decides this escrow's disputes, if delegated
+    pub dispute_contract: Option<Address>,
+    /// Timestamp after which an escrow that is not fully funded may be expired
+    pub funding_deadline: Option<u64>,
+    /// Platform fee in basis points on funds released to the beneficiary, locked in at creation
+    pub fee_bps: u32,
+    /// Further (token, amount) legs held alongside the primary `token` and `amount`
+    pub legs: Vec<AssetLeg>,
+}
+
+/// One additional token an escrow holds besides its primary token.
+/// Interest, funding schedules and top-ups apply to the primary token only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct AssetLeg {
+    /// Token of this leg
+    pub token: Address,
+    /// Amount of the token the escrow must hold
+    pub amount: i128,
+    /// Amount of the token deposited so far
+    pub funded_amount: i128,
+}
+
+/// Mirror of the dispute_resolution contract's `Dispute`, read back when settling
+/// a delegated dispute. Field names must match the panel contract's type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PanelDispute {
+    pub agreement_id: String,
+    pub raised_by: Address,
+    pub details_hash: String,
+    pub raised_at: u64,
+    pub resolved: bool,
+    pub resolved_at: Option<u64>,
+    pub votes_favor_landlord: u32,
+    pub votes_favor_tenant: u32,
+}
+
+/// Platform service fee configured by the admin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PlatformFee {
+    /// Fee in basis points on funds released to the beneficiary
+    pub fee_bps: u32,
+    /// Address the fee is paid to
+    pub treasury: Address,
+}
+
+/// An announced replacement of an escrow's arbiter, waiting out its delay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ArbiterReplacement {
+    /// Arbiter that will take over
+    pub new_arbiter: Address,
+    /// Timestamp the replacement was announced
+    pub proposed_at: u64,
+    /// Earliest timestamp the replacement can be executed
+    pub executable_at: u64,
+}
+
+/// Timeline of a dispute, kept after the dispute is settled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DisputeRecord {
+    /// Party who opened the dispute
+    pub initiated_by: Address,
+    /// Reason given when the dispute was opened
+    pub reason: String,
+    /// Timestamp the dispute was opened
+    pub opened_at: u64,
+    /// Last moment either party may submit evidence
+    pub response_deadline: u64,
+    /// Last moment the arbiter may resolve before the dispute escalates
+    pub resolution_deadline: u64,
+}
+
+/// A piece of evidence attached to a disputed escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct EvidenceEntry {
+    /// Party who submitted the evidence
+    pub submitted_by: Address,
+    /// Hash of the off-chain evidence (IPFS, etc.)
+    pub evidence_hash: String,
+    /// Timestamp of submission
+    pub submitted_at: u64,
+}
+
+/// One instalment of an escrow's funding schedule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct FundingInstalment {
+    /// Date by which the instalment should be deposited
+    pub due_date: u64,
+    /// Amount of the instalment
+    pub amount: i128,
+}
+
+/// A party allowed to approve releases, and how much its approval counts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Signer {
+    /// Address of the signer
+    pub address: Address,
+    /// Weight of the signer's approval (at least 1)
+    pub weight: u32,
 }
 
 /// Records approval of fund release by a single party.
@@ -53,6 +182,59 @@ pub struct ReleaseApproval {
     pub timestamp: u64,
 }
 
+/// What a signer did in an approval history entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ApprovalAction {
+    /// Signer approved release to the target
+    Approved = 0,
+    /// Signer withdrew an earlier approval
+    Revoked = 1,
+}
+
+/// Append-only audit entry for an approval or revocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ApprovalRecord {
+    /// Address of the signer
+    pub signer: Address,
+    /// Release target the action applies to (the beneficiary for split approvals)
+    pub release_to: Address,
+    /// Whether the approval was given or withdrawn
+    pub action: ApprovalAction,
+    /// Beneficiary share of the split the action applies to; None for full releases
+    pub beneficiary_amount: Option<i128>,
+    /// Timestamp of the action
+    pub timestamp: u64,
+}
+
+/// A proposal to divide the escrowed amount between depositor and beneficiary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ReleaseProposal {
+    /// Amount returned to the depositor
+    pub depositor_amount: i128,
+    /// Amount paid to the beneficiary (e.g. deductions for damage)
+    pub beneficiary_amount: i128,
+    /// Hash of the itemized deduction list, if any
+    pub deduction_reason: Option<String>,
+    /// Party who proposed the split
+    pub proposed_by: Address,
+    /// Timestamp of the proposal
+    pub proposed_at: u64,
+    /// Parties who approved this exact split (the proposer included)
+    pub approvals: Vec<Address>,
+}
+
+/// The role a party plays in an escrow, naming one of its per-party indexes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum PartyRole {
+    Depositor,
+    Beneficiary,
+    Arbiter,
+}
+
 /// Storage key variants for persistent storage.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -61,12 +243,56 @@ pub enum DataKey {
     Escrow(BytesN<32>),
     /// Store approvals for an escrow: DataKey::Approvals(escrow_id)
     Approvals(BytesN<32>),
-    /// Store dispute info: DataKey::DisputeInfo(escrow_id)
+    /// Store dispute info: DataKey::DisputeInfo(escrow_id) => DisputeRecord
     DisputeInfo(BytesN<32>),
-    /// Counter for total escrows created
+    /// Dispute evidence log: DataKey::Evidence(escrow_id) => Vec<EvidenceEntry>
+    Evidence(BytesN<32>),
+    /// Evidence and resolution windows (seconds) applied to new disputes
+    DisputeWindows,
+    /// Counter for live escrows (created and not cancelled)
     EscrowCount,
-    /// Per-target approval count: DataKey::ApprovalCount(escrow_id, release_to) => u32
+    /// Per-target approval weight: DataKey::ApprovalCount(escrow_id, release_to) => u32
     ApprovalCount(BytesN<32>, Address),
     /// Per-signer-per-target flag: DataKey::SignerApproved(escrow_id, signer, release_to) => bool
     SignerApproved(BytesN<32>, Address, Address),
+    /// Pending split proposal for an escrow: DataKey::ReleaseProposal(escrow_id)
+    ReleaseProposal(BytesN<32>),
+    /// Platform admin allowed to manage the arbiter registry
+    Admin,
+    /// Platform-approved arbiter flag: DataKey::ApprovedArbiter(arbiter) => bool
+    ApprovedArbiter(Address),
+    /// Annual interest rate in bps applied to newly funded escrows
+    InterestRate,
+    /// Platform-funded interest reserve per token: DataKey::InterestReserve(token) => i128
+    InterestReserve(Address),
+    /// Number of approval history records: DataKey::ApprovalHistoryLen(escrow_id) => u32
+    ApprovalHistoryLen(BytesN<32>),
+    /// Append-only approval history record:
+    /// DataKey::ApprovalHistoryEntry(escrow_id, position) => ApprovalRecord
+    ApprovalHistoryEntry(BytesN<32>, u32),
+    /// dispute_resolution contract escrows may delegate their disputes to
+    DisputeContract,
+    /// Agreed funding schedule: DataKey::FundingSchedule(escrow_id) => Vec<FundingInstalment>
+    FundingSchedule(BytesN<32>),
+    /// Platform service fee configuration => PlatformFee
+    PlatformFee,
+    /// Pending arbiter replacement: DataKey::ArbiterReplacement(escrow_id) => ArbiterReplacement
+    ArbiterReplacement(BytesN<32>),
+    /// Number of escrows in a party's index: DataKey::PartyIndexLen(role, party) => u32
+    PartyIndexLen(PartyRole, Address),
+    /// Escrow at a position in a party's index:
+    /// DataKey::PartyIndexEntry(role, party, position) => BytesN<32>
+    PartyIndexEntry(PartyRole, Address, u32),
+    /// Position of an escrow in a party's index:
+    /// DataKey::PartyIndexSlot(role, party, escrow_id) => u32
+    PartyIndexSlot(PartyRole, Address, BytesN<32>),
+    /// Number of a party's escrows in a status:
+    /// DataKey::StatusIndexLen(role, party, status) => u32
+    StatusIndexLen(PartyRole, Address, EscrowStatus),
+    /// Escrow at a position among a party's escrows in a status:
+    /// DataKey::StatusIndexEntry(role, party, status, position) => BytesN<32>
+    StatusIndexEntry(PartyRole, Address, EscrowStatus, u32),
+    /// Position of an escrow among a party's escrows in its status:
+    /// DataKey::StatusIndexSlot(role, party, status, escrow_id) => u32
+    StatusIndexSlot(PartyRole, Address, EscrowStatus, BytesN<32>),
 }
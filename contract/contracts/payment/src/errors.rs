@@ -5,6 +5,8 @@ use soroban_sdk::contracterror;
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum PaymentError {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
     /// Payment record not found
     PaymentNotFound = 11,
     /// Payment processing failed
@@ -21,4 +23,10 @@ pub enum PaymentError {
     PaymentNotDue = 18,
     /// Invalid amount provided
     InvalidAmount = 5,
+    /// Commission and fee rates exceed 100% in basis points
+    InvalidCommissionRate = 7,
+    /// Contract has not been initialized with a chioma contract
+    NotInitialized = 19,
+    /// The chioma contract rejected the call or returned data that does not decode
+    ChiomaCallFailed = 20,
 }
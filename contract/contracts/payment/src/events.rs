@@ -0,0 +1,27 @@
+//! Events published by the Payment contract.
+use soroban_sdk::{contractevent, Address, Env};
+
+/// Event emitted when the admin points the contract at a different chioma contract
+/// Topics: ["chioma_set", admin: Address]
+#[contractevent(topics = ["chioma_set"])]
+pub struct ChiomaContractSet {
+    #[topic]
+    pub admin: Address,
+    pub old_contract: Address,
+    pub new_contract: Address,
+}
+
+/// Helper function to emit chioma contract changed event
+pub(crate) fn chioma_contract_set(
+    env: &Env,
+    admin: Address,
+    old_contract: Address,
+    new_contract: Address,
+) {
+    ChiomaContractSet {
+        admin,
+        old_contract,
+        new_contract,
+    }
+    .publish(env);
+}
@@ -0,0 +1,137 @@
+//! Per-agreement payment ledger: immutable payment records numbered per agreement,
+//! running totals, and a per-tenant index for paged lookups.
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::storage::DataKey;
+use crate::types::{PaymentLedger, PaymentRecord};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Largest page returned by the paged payment queries
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Running totals for an agreement; all zero before its first payment
+pub fn get_ledger(env: &Env, agreement_id: &String) -> PaymentLedger {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Ledger(agreement_id.clone()))
+        .unwrap_or_default()
+}
+
+/// Number the next payment on an agreement
+pub fn next_payment_number(env: &Env, agreement_id: &String) -> u32 {
+    get_ledger(env, agreement_id).payment_count + 1
+}
+
+/// Append a payment record to its agreement's ledger and its tenant's index,
+/// and update the running totals. `record.payment_number` must come from
+/// `next_payment_number`.
+pub fn append(env: &Env, record: &PaymentRecord) {
+    let agreement_id = &record.agreement_id;
+
+    let record_key = DataKey::PaymentRecord(agreement_id.clone(), record.payment_number);
+    set_persistent(env, &record_key, record);
+
+    let mut ledger = get_ledger(env, agreement_id);
+    ledger.payment_count = record.payment_number;
+    ledger.total_paid += record.amount;
+    ledger.landlord_paid += record.landlord_amount;
+    ledger.agent_paid += record.agent_amount;
+    ledger.platform_paid += record.amount - record.landlord_amount - record.agent_amount;
+    ledger.last_payment_at = record.timestamp;
+    set_persistent(env, &DataKey::Ledger(agreement_id.clone()), &ledger);
+
+    let tenant_count = get_tenant_payment_count(env, &record.tenant);
+    set_persistent(
+        env,
+        &DataKey::TenantPayment(record.tenant.clone(), tenant_count),
+        &(agreement_id.clone(), record.payment_number),
+    );
+    set_persistent(
+        env,
+        &DataKey::TenantPaymentCount(record.tenant.clone()),
+        &(tenant_count + 1),
+    );
+
+    let payment_count: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PaymentCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::PaymentCount, &(payment_count + 1));
+}
+
+/// A single payment on an agreement
+pub fn get_payment(env: &Env, agreement_id: &String, payment_number: u32) -> Option<PaymentRecord> {
+    env.storage().persistent().get(&DataKey::PaymentRecord(
+        agreement_id.clone(),
+        payment_number,
+    ))
+}
+
+/// Up to `limit` payments on an agreement in payment order, skipping the first `start`
+pub fn payments_by_agreement(
+    env: &Env,
+    agreement_id: &String,
+    start: u32,
+    limit: u32,
+) -> Vec<PaymentRecord> {
+    let count = get_ledger(env, agreement_id).payment_count;
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut page = Vec::new(env);
+    for number in start.saturating_add(1)..=end {
+        if let Some(record) = get_payment(env, agreement_id, number) {
+            page.push_back(record);
+        }
+    }
+    page
+}
+
+/// Up to `limit` payments made by a tenant across agreements in payment order,
+/// skipping the first `start`
+pub fn payments_by_tenant(
+    env: &Env,
+    tenant: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<PaymentRecord> {
+    let count = get_tenant_payment_count(env, tenant);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut page = Vec::new(env);
+    for i in start..end {
+        let entry: Option<(String, u32)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TenantPayment(tenant.clone(), i));
+        if let Some(record) =
+            entry.and_then(|(agreement_id, number)| get_payment(env, &agreement_id, number))
+        {
+            page.push_back(record);
+        }
+    }
+    page
+}
+
+/// Number of payments a tenant has made across agreements
+pub fn get_tenant_payment_count(env: &Env, tenant: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TenantPaymentCount(tenant.clone()))
+        .unwrap_or(0)
+}
+
+fn set_persistent<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(
+    env: &Env,
+    key: &DataKey,
+    value: &V,
+) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_BUMP);
+}
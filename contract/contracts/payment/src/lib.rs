@@ -4,11 +4,14 @@
 //! Payment Contract
 //!
 //! Handles rent payment processing with automatic commission splitting
-//! and payment record management.
+//! and payment record management. Agreements live in the chioma contract;
+//! payments are recorded back on them through chioma's `record_payment`.
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
 pub mod errors;
+pub mod events;
+pub mod ledger;
 pub mod payment_impl;
 pub mod storage;
 pub mod types;
@@ -18,36 +21,82 @@ mod tests;
 
 // Re-export public APIs
 pub use errors::PaymentError;
-pub use payment_impl::{calculate_payment_split, create_payment_record};
+pub use payment_impl::{
+    calculate_payment_split, create_payment_record, late_fee, load_agreement, load_config,
+    record_payment, rent_due,
+};
 pub use storage::DataKey;
-pub use types::{PaymentRecord, PaymentSplit};
+pub use types::{Config, PaymentLedger, PaymentRecord, PaymentSplit};
 
 use crate::errors::PaymentError as Error;
+use crate::payment_impl::accepts_payments;
 use crate::storage::DataKey as StorageKey;
-use crate::types::{AgreementStatus, RentAgreement};
 
 #[contract]
 pub struct PaymentContract;
 
 #[contractimpl]
 impl PaymentContract {
-    /// Sets the platform fee collector address
-    pub fn set_platform_fee_collector(env: Env, collector: Address) {
-        collector.require_auth();
+    /// Initialize the contract with an admin and the chioma contract that owns
+    /// the rent agreements
+    pub fn initialize(env: Env, admin: Address, chioma_contract: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&StorageKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(&StorageKey::Admin, &admin);
         env.storage()
             .instance()
-            .set(&StorageKey::PlatformFeeCollector, &collector);
+            .set(&StorageKey::ChiomaContract, &chioma_contract);
+        env.storage().instance().extend_ttl(500000, 500000);
+
+        Ok(())
     }
 
-    /// Get a payment record by ID
-    pub fn get_payment(env: Env, payment_id: String) -> Result<PaymentRecord, Error> {
+    /// Point the contract at a different chioma contract (admin only).
+    /// Publishes the old and new addresses so the change is visible off-chain.
+    pub fn set_chioma_contract(env: Env, chioma_contract: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        admin.require_auth();
+
+        let old_contract: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::ChiomaContract)
+            .ok_or(Error::NotInitialized)?;
+
         env.storage()
-            .persistent()
-            .get(&StorageKey::Payment(payment_id))
-            .ok_or(Error::PaymentNotFound)
+            .instance()
+            .set(&StorageKey::ChiomaContract, &chioma_contract);
+        env.storage().instance().extend_ttl(500000, 500000);
+
+        events::chioma_contract_set(&env, admin, old_contract, chioma_contract);
+
+        Ok(())
     }
 
-    /// Get total payment count
+    /// Get the chioma contract agreements are read from
+    pub fn get_chioma_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&StorageKey::ChiomaContract)
+    }
+
+    /// Get a payment on an agreement by its payment number (numbered from 1)
+    pub fn get_payment(
+        env: Env,
+        agreement_id: String,
+        payment_number: u32,
+    ) -> Result<PaymentRecord, Error> {
+        ledger::get_payment(&env, &agreement_id, payment_number).ok_or(Error::PaymentNotFound)
+    }
+
+    /// Get total payment count across all agreements
     pub fn get_payment_count(env: Env) -> u32 {
         env.storage()
             .instance()
@@ -57,48 +106,42 @@ impl PaymentContract {
 
     /// Get total amount paid for a specific agreement
     pub fn get_total_paid(env: Env, agreement_id: String) -> Result<i128, Error> {
-        let payment_count: u32 = env
-            .storage()
-            .instance()
-            .get(&StorageKey::PaymentCount)
-            .unwrap_or(0);
-
-        let mut total: i128 = 0;
-
-        for i in 0..payment_count {
-            let payment_id = Self::u32_to_string(&env, i);
-            if let Some(payment) = env
-                .storage()
-                .persistent()
-                .get::<StorageKey, PaymentRecord>(&StorageKey::Payment(payment_id))
-            {
-                if payment.agreement_id == agreement_id {
-                    total += payment.amount;
-                }
-            }
-        }
+        Ok(ledger::get_ledger(&env, &agreement_id).total_paid)
+    }
 
-        Ok(total)
+    /// Get the running payment totals for an agreement
+    pub fn get_payment_ledger(env: Env, agreement_id: String) -> PaymentLedger {
+        ledger::get_ledger(&env, &agreement_id)
     }
 
-    fn u32_to_string(env: &Env, num: u32) -> String {
-        match num {
-            0 => String::from_str(env, "0"),
-            1 => String::from_str(env, "1"),
-            2 => String::from_str(env, "2"),
-            3 => String::from_str(env, "3"),
-            4 => String::from_str(env, "4"),
-            5 => String::from_str(env, "5"),
-            6 => String::from_str(env, "6"),
-            7 => String::from_str(env, "7"),
-            8 => String::from_str(env, "8"),
-            9 => String::from_str(env, "9"),
-            10 => String::from_str(env, "10"),
-            _ => String::from_str(env, "unknown"),
-        }
+    /// List payments on an agreement in payment order, skipping the first `start`
+    /// and returning at most `limit` (capped at `ledger::MAX_PAGE_SIZE`)
+    pub fn get_payments_by_agreement(
+        env: Env,
+        agreement_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<PaymentRecord> {
+        ledger::payments_by_agreement(&env, &agreement_id, start, limit)
     }
 
-    /// Process rent payment with 90/10 landlord/platform split
+    /// List a tenant's payments across agreements in payment order, skipping the
+    /// first `start` and returning at most `limit` (capped at `ledger::MAX_PAGE_SIZE`)
+    pub fn get_payments_by_tenant(
+        env: Env,
+        tenant: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<PaymentRecord> {
+        ledger::payments_by_tenant(&env, &tenant, start, limit)
+    }
+
+    /// Process rent payment, split between landlord, agent and platform.
+    /// The platform fee is the rate fixed on the agreement when chioma created it and
+    /// is paid to the collector in chioma's `Config`; the agent's
+    /// commission is paid only when the agreement has an agent. A payment made after
+    /// the agreement's grace period must include its late fee, which goes to the
+    /// landlord in full.
     /// Follows checks-effects-interactions pattern for reentrancy safety
     pub fn pay_rent(
         env: Env,
@@ -111,15 +154,12 @@ impl PaymentContract {
         // Authorization
         from.require_auth();
 
-        // Load agreement
-        let mut agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        // Load agreement from chioma
+        let agreement = load_agreement(&env, &agreement_id)?;
 
         // Validation
-        if agreement.status != AgreementStatus::Active {
+        let current_time = env.ledger().timestamp();
+        if !accepts_payments(&agreement, current_time) {
             return Err(Error::AgreementNotActive);
         }
 
@@ -131,46 +171,61 @@ impl PaymentContract {
             return Err(Error::InvalidPaymentAmount);
         }
 
-        if payment_amount != agreement.monthly_rent {
-            return Err(Error::InvalidPaymentAmount);
-        }
-
-        let current_time = env.ledger().timestamp();
         if current_time < agreement.next_payment_due {
             return Err(Error::PaymentNotDue);
         }
 
-        // Calculate 90/10 split
-        let landlord_amount = (payment_amount * 90) / 100;
-        let platform_amount = payment_amount - landlord_amount;
+        let rent = rent_due(&agreement);
+        let late_fee = late_fee(&agreement, current_time);
+        if payment_amount != rent + late_fee {
+            return Err(Error::InvalidPaymentAmount);
+        }
 
-        let platform_collector: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::PlatformFeeCollector)
-            .ok_or(Error::PaymentFailed)?;
-
-        // Effects: Update state BEFORE external calls
-        let payment_month = agreement.payment_history.len();
-        agreement.payment_history.set(
-            payment_month,
-            PaymentSplit {
-                landlord_amount,
-                platform_amount,
-                token: agreement.payment_token.clone(),
-                payment_date: current_time,
-            },
-        );
-        agreement.next_payment_due = current_time + 2_592_000; // 30 days
-
-        env.storage()
-            .persistent()
-            .set(&StorageKey::Agreement(agreement_id.clone()), &agreement);
+        // Calculate the split at the fee fixed on the agreement; without an agent the
+        // commission stays with the landlord
+        let config = load_config(&env)?;
+        let commission_rate = if agreement.agent.is_some() {
+            agreement.agent_commission_rate
+        } else {
+            0
+        };
+        let (landlord_amount, agent_amount, platform_amount) =
+            calculate_payment_split(&rent, &commission_rate, &agreement.platform_fee_bps)?;
+        let landlord_amount = landlord_amount + late_fee;
+
+        // Effects: Record the payment on the agreement in chioma BEFORE moving funds
+        record_payment(
+            &env,
+            &agreement_id,
+            &from,
+            landlord_amount,
+            agent_amount,
+            platform_amount,
+        )?;
+
+        let payment_record = create_payment_record(
+            &env,
+            &agreement_id,
+            payment_amount,
+            landlord_amount,
+            agent_amount,
+            &from,
+            ledger::next_payment_number(&env, &agreement_id),
+            current_time,
+        )?;
+        ledger::append(&env, &payment_record);
 
         // Interactions: External calls AFTER state updates
         let token_client = token::Client::new(&env, &agreement.payment_token);
         token_client.transfer(&from, &agreement.landlord, &landlord_amount);
-        token_client.transfer(&from, &platform_collector, &platform_amount);
+        if let Some(agent) = &agreement.agent {
+            if agent_amount > 0 {
+                token_client.transfer(&from, agent, &agent_amount);
+            }
+        }
+        if platform_amount > 0 {
+            token_client.transfer(&from, &config.fee_collector, &platform_amount);
+        }
 
         Ok(())
     }
@@ -181,13 +236,7 @@ impl PaymentContract {
         agreement_id: String,
         month: u32,
     ) -> Result<PaymentSplit, Error> {
-        let agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id))
-            .ok_or(Error::AgreementNotFound)?;
-
-        agreement
+        load_agreement(&env, &agreement_id)?
             .payment_history
             .get(month)
             .ok_or(Error::PaymentNotFound)
@@ -1,9 +1,131 @@
 //! Payment processing implementation.
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Env, IntoVal, InvokeError, String, Symbol, Val, Vec};
 
 use crate::errors::PaymentError;
 use crate::storage::DataKey;
-use crate::types::{AgreementStatus, PaymentRecord, RentAgreement};
+use crate::types::{AgreementStatus, Config, ContractState, PaymentRecord, RentAgreement};
+
+// Codes of chioma's `RentalError` that payments surface as their own errors
+const CHIOMA_INVALID_AMOUNT: u32 = 5;
+const CHIOMA_AGREEMENT_NOT_ACTIVE: u32 = 10;
+const CHIOMA_AGREEMENT_NOT_FOUND: u32 = 13;
+
+/// Call `func` on the configured chioma contract.
+///
+/// Chioma's errors for a missing or inactive agreement and a rejected amount map
+/// to the matching `PaymentError`; any other error, a trap, or a result that does
+/// not decode into `T` fails with `ChiomaCallFailed` instead of trapping.
+fn call_chioma<T>(env: &Env, func: &str, args: Vec<Val>) -> Result<T, PaymentError>
+where
+    T: soroban_sdk::TryFromVal<Env, Val>,
+{
+    let chioma: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::ChiomaContract)
+        .ok_or(PaymentError::NotInitialized)?;
+
+    match env.try_invoke_contract::<T, InvokeError>(&chioma, &Symbol::new(env, func), args) {
+        Ok(Ok(value)) => Ok(value),
+        Err(Ok(InvokeError::Contract(CHIOMA_INVALID_AMOUNT))) => {
+            Err(PaymentError::InvalidPaymentAmount)
+        }
+        Err(Ok(InvokeError::Contract(CHIOMA_AGREEMENT_NOT_ACTIVE))) => {
+            Err(PaymentError::AgreementNotActive)
+        }
+        Err(Ok(InvokeError::Contract(CHIOMA_AGREEMENT_NOT_FOUND))) => {
+            Err(PaymentError::AgreementNotFound)
+        }
+        _ => Err(PaymentError::ChiomaCallFailed),
+    }
+}
+
+/// Load a rent agreement from the configured chioma contract
+pub fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, PaymentError> {
+    let agreement: Option<RentAgreement> = call_chioma(
+        env,
+        "get_agreement",
+        soroban_sdk::vec![env, agreement_id.into_val(env)],
+    )?;
+
+    agreement.ok_or(PaymentError::AgreementNotFound)
+}
+
+/// Load chioma's platform configuration, which sets the platform fee and its collector
+pub fn load_config(env: &Env) -> Result<Config, PaymentError> {
+    let state: Option<ContractState> = call_chioma(env, "get_state", soroban_sdk::vec![env])?;
+
+    state
+        .map(|state| state.config)
+        .ok_or(PaymentError::PaymentFailed)
+}
+
+/// Record a collected payment on the agreement in chioma. Chioma only accepts
+/// this call from its configured payment contract; it fails the whole payment
+/// if the shares do not add up to the rent due.
+pub fn record_payment(
+    env: &Env,
+    agreement_id: &String,
+    payer: &Address,
+    landlord_amount: i128,
+    agent_amount: i128,
+    platform_amount: i128,
+) -> Result<(), PaymentError> {
+    call_chioma::<()>(
+        env,
+        "record_payment",
+        soroban_sdk::vec![
+            env,
+            agreement_id.into_val(env),
+            payer.into_val(env),
+            landlord_amount.into_val(env),
+            agent_amount.into_val(env),
+            platform_amount.into_val(env),
+        ],
+    )
+}
+
+/// Rent payable for the next unpaid period: the monthly rent for a period of the
+/// term, the holdover rent for a period after it, matching chioma's `current_rent`
+pub fn rent_due(agreement: &RentAgreement) -> i128 {
+    let term_periods = agreement
+        .end_date
+        .saturating_sub(agreement.start_date)
+        .div_ceil(PAYMENT_PERIOD);
+    let period = u64::from(agreement.payment_history.len());
+    if period >= term_periods && agreement.holdover_rent_bps > 0 {
+        agreement.monthly_rent * agreement.holdover_rent_bps as i128 / MAX_BPS as i128
+    } else {
+        agreement.monthly_rent
+    }
+}
+
+/// Late fee owed on a payment made at `now`: `late_fee_bps` of the rent due once
+/// `late_fee_grace_period` seconds have passed since the payment fell due. Chioma
+/// applies the same rule when it records the payment.
+pub fn late_fee(agreement: &RentAgreement, now: u64) -> i128 {
+    if now
+        > agreement
+            .next_payment_due
+            .saturating_add(agreement.late_fee_grace_period)
+    {
+        rent_due(agreement) * agreement.late_fee_bps as i128 / MAX_BPS as i128
+    } else {
+        0
+    }
+}
+
+/// Whether rent can be paid on the agreement at `now`: during the term while it is
+/// Active, and while it is held over until the holdover ends
+pub fn accepts_payments(agreement: &RentAgreement, now: u64) -> bool {
+    match agreement.status {
+        AgreementStatus::Active => now <= agreement.end_date,
+        AgreementStatus::Holdover => agreement
+            .holdover_ends_at
+            .is_none_or(|ends_at| now < ends_at),
+        _ => false,
+    }
+}
 
 /// Create an immutable payment record
 pub fn create_payment_record(
@@ -27,101 +149,37 @@ pub fn create_payment_record(
     })
 }
 
-/// Calculate payment split between landlord and agent
-pub fn calculate_payment_split(amount: &i128, commission_rate: &u32) -> (i128, i128) {
-    // commission_rate is in basis points (1 basis point = 0.01%)
-    let agent_amount = (amount * (*commission_rate as i128)) / 10000;
-    let landlord_amount = amount - agent_amount;
-    (landlord_amount, agent_amount)
-}
-
-/// Process rent payment with automatic commission splitting
-/// This is the alternate implementation used by RentalContract
-#[allow(deprecated)]
-#[allow(dead_code)]
-pub fn pay_rent_with_agent(
-    env: Env,
-    agreement_id: String,
-    token: Address,
-    amount: i128,
-) -> Result<(), PaymentError> {
-    use soroban_sdk::token::Client as TokenClient;
-
-    // Load agreement
-    let mut agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(PaymentError::InvalidAmount)?;
-
-    // Validate agreement is active
-    if agreement.status != AgreementStatus::Active {
-        return Err(PaymentError::AgreementNotActive);
-    }
-
-    // Validate amount is strictly positive to prevent logical errors
-    if amount <= 0 {
+/// Basis-point denominator used for commission and fee rates (100% = 10,000 bps)
+pub const MAX_BPS: u32 = 10_000;
+
+/// Length of a rent period in seconds, as chioma schedules them
+pub const PAYMENT_PERIOD: u64 = 2_592_000;
+
+/// Calculate payment split between landlord, agent and platform
+///
+/// `commission_rate` and `fee_bps` are basis points (1 basis point = 0.01%) of `amount`.
+/// The agent and platform shares are each rounded down and the landlord receives the
+/// remainder, so the three parts always add up to `amount`. This is the only split
+/// routine; chioma records the shares computed here.
+/// Returns `(landlord_amount, agent_amount, platform_amount)`.
+pub fn calculate_payment_split(
+    amount: &i128,
+    commission_rate: &u32,
+    fee_bps: &u32,
+) -> Result<(i128, i128, i128), PaymentError> {
+    if *amount < 0 {
         return Err(PaymentError::InvalidAmount);
     }
 
-    // Validate amount matches monthly rent exactly
-    if amount != agreement.monthly_rent {
-        return Err(PaymentError::InvalidAmount);
+    if commission_rate
+        .checked_add(*fee_bps)
+        .is_none_or(|total| total > MAX_BPS)
+    {
+        return Err(PaymentError::InvalidCommissionRate);
     }
 
-    // Authorize tenant
-    agreement.tenant.require_auth();
-
-    // Calculate payment split
-    let (landlord_amount, agent_amount) =
-        calculate_payment_split(&amount, &agreement.agent_commission_rate);
-
-    // Execute atomic token transfers
-    let token_client = TokenClient::new(&env, &token);
-
-    // Transfer to landlord
-    token_client.transfer(&agreement.tenant, &agreement.landlord, &landlord_amount);
-
-    // Transfer to agent if present
-    if let Some(agent_address) = &agreement.agent {
-        if agent_amount > 0 {
-            token_client.transfer(&agreement.tenant, agent_address, &agent_amount);
-        }
-    }
-
-    // Create payment record
-    let timestamp = env.ledger().timestamp();
-    let payment_record = create_payment_record(
-        &env,
-        &agreement_id,
-        amount,
-        landlord_amount,
-        agent_amount,
-        &agreement.tenant,
-        agreement.payment_count + 1,
-        timestamp,
-    )?;
-
-    // Update agreement totals
-    agreement.total_rent_paid += amount;
-    agreement.payment_count += 1;
-
-    // Persist updated agreement
-    env.storage()
-        .persistent()
-        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
-
-    // Persist payment record
-    env.storage().persistent().set(
-        &DataKey::PaymentRecord(agreement_id.clone(), agreement.payment_count),
-        &payment_record,
-    );
-
-    // Emit event
-    env.events().publish(
-        (String::from_str(&env, "rent_paid"), agreement_id),
-        (amount, landlord_amount, agent_amount, timestamp),
-    );
-
-    Ok(())
+    let agent_amount = (amount * (*commission_rate as i128)) / MAX_BPS as i128;
+    let platform_amount = (amount * (*fee_bps as i128)) / MAX_BPS as i128;
+    let landlord_amount = amount - agent_amount - platform_amount;
+    Ok((landlord_amount, agent_amount, platform_amount))
 }
@@ -1,18 +1,22 @@
 //! Storage key definitions for the Payment contract.
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, String};
 
 /// Storage key variants for persistent storage.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    /// Store payment by ID
-    Payment(String),
-    /// Store payment record by agreement ID and payment number
+    /// Store payment record by agreement ID and payment number (numbered from 1)
     PaymentRecord(String, u32),
+    /// Running totals of the payments on an agreement
+    Ledger(String),
+    /// The tenant's n-th payment (from 0), as (agreement ID, payment number)
+    TenantPayment(Address, u32),
+    /// Number of payments a tenant has made
+    TenantPaymentCount(Address),
     /// Counter for total payments
     PaymentCount,
-    /// Platform fee collector address
-    PlatformFeeCollector,
-    /// Agreement storage (for reading agreement data)
-    Agreement(String),
+    /// Admin set at initialization
+    Admin,
+    /// Chioma contract that owns the rent agreements
+    ChiomaContract,
 }
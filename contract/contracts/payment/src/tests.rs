@@ -2,8 +2,72 @@
 
 use crate::payment_impl::*;
 use crate::types::*;
-use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
-use soroban_sdk::{testutils::Address as _, Address, Env, Map, String};
+use crate::{PaymentContract, PaymentContractClient, PaymentError};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient as TokenAdminClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, Map, String, Symbol};
+
+/// Stands in for the chioma contract: serves stored agreements and records payments
+/// on them the way chioma's `record_payment` does.
+#[contract]
+pub struct MockChiomaContract;
+
+#[contractimpl]
+impl MockChiomaContract {
+    pub fn set_agreement(env: Env, agreement: RentAgreement) {
+        env.storage()
+            .persistent()
+            .set(&agreement.agreement_id, &agreement);
+    }
+
+    pub fn set_config(env: Env, fee_bps: u32, fee_collector: Address) {
+        let state = ContractState {
+            admin: fee_collector.clone(),
+            config: Config {
+                fee_bps,
+                fee_collector,
+                paused: false,
+            },
+            initialized: true,
+        };
+        env.storage().instance().set(&0u32, &state);
+    }
+
+    pub fn get_state(env: Env) -> Option<ContractState> {
+        env.storage().instance().get(&0u32)
+    }
+
+    pub fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement> {
+        env.storage().persistent().get(&agreement_id)
+    }
+
+    pub fn record_payment(
+        env: Env,
+        agreement_id: String,
+        payer: Address,
+        landlord_amount: i128,
+        agent_amount: i128,
+        platform_amount: i128,
+    ) {
+        let mut agreement: RentAgreement = env.storage().persistent().get(&agreement_id).unwrap();
+        let period = agreement.payment_history.len();
+        agreement.payment_history.set(
+            period,
+            PaymentSplit {
+                landlord_amount,
+                agent_amount,
+                platform_amount,
+                token: agreement.payment_token.clone(),
+                payment_date: env.ledger().timestamp(),
+                payer,
+            },
+        );
+        agreement.total_rent_paid += landlord_amount + agent_amount + platform_amount;
+        agreement.payment_count += 1;
+        agreement.next_payment_due += PAYMENT_PERIOD;
+        env.storage().persistent().set(&agreement_id, &agreement);
+    }
+}
 
 // Helper function to create a test agreement
 fn create_test_agreement(
@@ -24,16 +88,26 @@ fn create_test_agreement(
         agent,
         monthly_rent,
         agent_commission_rate: commission_rate,
+        platform_fee_bps: 0,
         status,
         total_rent_paid: 0,
         payment_count: 0,
         security_deposit: 0,
         start_date: 0,
-        end_date: 0,
+        end_date: 12 * PAYMENT_PERIOD,
         signed_at: None,
         payment_token,
         next_payment_due: 0,
         payment_history: Map::new(env),
+        late_fee_bps: 0,
+        late_fee_grace_period: 0,
+        terms_hash: None,
+        template_id: None,
+        deposit_released_at: None,
+        holdover_rent_bps: 0,
+        holdover_notice_period: 0,
+        holdover_started_at: None,
+        holdover_ends_at: None,
     }
 }
 
@@ -44,33 +118,69 @@ fn create_token(env: &Env, admin: &Address) -> Address {
 
 #[test]
 fn test_calculate_payment_split_no_commission() {
-    let (landlord, agent) = calculate_payment_split(&1000, &0);
+    let (landlord, agent, platform) = calculate_payment_split(&1000, &0, &0).unwrap();
     assert_eq!(landlord, 1000);
     assert_eq!(agent, 0);
+    assert_eq!(platform, 0);
 }
 
 #[test]
 fn test_calculate_payment_split_5_percent() {
     // Test with 5% commission (500 basis points)
-    let (landlord, agent) = calculate_payment_split(&1000, &500);
+    let (landlord, agent, platform) = calculate_payment_split(&1000, &500, &0).unwrap();
     assert_eq!(landlord, 950);
     assert_eq!(agent, 50);
+    assert_eq!(platform, 0);
 }
 
 #[test]
 fn test_calculate_payment_split_10_percent() {
     // Test with 10% commission (1000 basis points)
-    let (landlord, agent) = calculate_payment_split(&2000, &1000);
+    let (landlord, agent, platform) = calculate_payment_split(&2000, &1000, &0).unwrap();
     assert_eq!(landlord, 1800);
     assert_eq!(agent, 200);
+    assert_eq!(platform, 0);
 }
 
 #[test]
 fn test_calculate_payment_split_2_5_percent() {
     // Test with 2.5% commission (250 basis points)
-    let (landlord, agent) = calculate_payment_split(&10000, &250);
+    let (landlord, agent, platform) = calculate_payment_split(&10000, &250, &0).unwrap();
     assert_eq!(landlord, 9750);
     assert_eq!(agent, 250);
+    assert_eq!(platform, 0);
+}
+
+#[test]
+fn test_calculate_payment_split_with_platform_fee() {
+    // 5% commission and 1% platform fee
+    let (landlord, agent, platform) = calculate_payment_split(&10000, &500, &100).unwrap();
+    assert_eq!(landlord, 9400);
+    assert_eq!(agent, 500);
+    assert_eq!(platform, 100);
+}
+
+#[test]
+fn test_calculate_payment_split_parts_add_up() {
+    // Rounding dust goes to the landlord
+    let (landlord, agent, platform) = calculate_payment_split(&999, &333, &250).unwrap();
+    assert_eq!(agent, 33);
+    assert_eq!(platform, 24);
+    assert_eq!(landlord + agent + platform, 999);
+}
+
+#[test]
+fn test_calculate_payment_split_rejects_rates_above_100_percent() {
+    let result = calculate_payment_split(&1000, &9500, &600);
+    assert_eq!(result, Err(crate::PaymentError::InvalidCommissionRate));
+}
+
+#[test]
+fn test_calculate_payment_split_rejects_overflowing_rates() {
+    let result = calculate_payment_split(&1000, &u32::MAX, &1);
+    assert_eq!(result, Err(crate::PaymentError::InvalidCommissionRate));
+    let result = calculate_payment_split(&1000, &1, &u32::MAX);
+    assert_eq!(result, Err(crate::PaymentError::InvalidCommissionRate));
 }
 
 #[test]
@@ -146,3 +256,484 @@ fn test_agreement_with_agent() {
     assert_eq!(agreement.agent, Some(agent));
     assert_eq!(agreement.agent_commission_rate, 500);
 }
+
+#[test]
+fn test_rent_due_uses_holdover_rent() {
+    let env = Env::default();
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    let mut agreement = create_test_agreement(
+        &env,
+        "agreement_3",
+        &tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    agreement.end_date = 2 * PAYMENT_PERIOD;
+    agreement.holdover_rent_bps = 11_000;
+    assert_eq!(rent_due(&agreement), 1000);
+    assert!(accepts_payments(&agreement, 0));
+    // The term is over but the agreement was never held over
+    assert!(!accepts_payments(&agreement, 2 * PAYMENT_PERIOD + 1));
+
+    // Term periods still unpaid in holdover are owed at the monthly rent
+    agreement.status = AgreementStatus::Holdover;
+    assert_eq!(rent_due(&agreement), 1000);
+    assert!(accepts_payments(&agreement, 2 * PAYMENT_PERIOD + 1));
+
+    for period in 0..2 {
+        agreement.payment_history.set(
+            period,
+            PaymentSplit {
+                landlord_amount: 1000,
+                agent_amount: 0,
+                platform_amount: 0,
+                token: token.clone(),
+                payment_date: 0,
+                payer: tenant.clone(),
+            },
+        );
+    }
+    assert_eq!(rent_due(&agreement), 1100);
+
+    // No rent once the holdover has ended
+    agreement.holdover_ends_at = Some(3 * PAYMENT_PERIOD);
+    assert!(accepts_payments(&agreement, 3 * PAYMENT_PERIOD - 1));
+    assert!(!accepts_payments(&agreement, 3 * PAYMENT_PERIOD));
+
+    agreement.status = AgreementStatus::Completed;
+    assert!(!accepts_payments(&agreement, 0));
+}
+
+fn setup_payment(env: &Env) -> (PaymentContractClient<'_>, MockChiomaContractClient<'_>) {
+    let chioma_id = env.register(MockChiomaContract, ());
+    let payment_id = env.register(PaymentContract, ());
+    let payment = PaymentContractClient::new(env, &payment_id);
+    payment.initialize(&Address::generate(env), &chioma_id);
+    (payment, MockChiomaContractClient::new(env, &chioma_id))
+}
+
+#[test]
+fn test_pay_rent_records_payment_in_chioma() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (payment, chioma) = setup_payment(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+    TokenAdminClient::new(&env, &token).mint(&tenant, &5000);
+
+    chioma.set_config(&1000, &collector);
+    let mut agreement = create_test_agreement(
+        &env,
+        "agreement_4",
+        &tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    agreement.platform_fee_bps = 1000;
+    chioma.set_agreement(&agreement);
+
+    let agreement_id = String::from_str(&env, "agreement_4");
+    payment.pay_rent(&tenant, &agreement_id, &1000);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&landlord), 900);
+    assert_eq!(token_client.balance(&collector), 100);
+    assert_eq!(token_client.balance(&tenant), 4000);
+
+    let agreement = chioma.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(agreement.total_rent_paid, 1000);
+
+    let split = payment.get_payment_split(&agreement_id, &0);
+    assert_eq!(split.landlord_amount, 900);
+    assert_eq!(split.platform_amount, 100);
+    assert_eq!(split.payer, tenant);
+
+    // The next period is not due yet
+    let result = payment.try_pay_rent(&tenant, &agreement_id, &1000);
+    assert_eq!(result, Err(Ok(PaymentError::PaymentNotDue)));
+}
+
+#[test]
+fn test_pay_rent_unknown_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (payment, _) = setup_payment(&env);
+    let tenant = Address::generate(&env);
+
+    let result = payment.try_pay_rent(&tenant, &String::from_str(&env, "missing"), &1000);
+    assert_eq!(result, Err(Ok(PaymentError::AgreementNotFound)));
+}
+
+#[test]
+fn test_pay_rent_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let payment = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+    let tenant = Address::generate(&env);
+
+    let result = payment.try_pay_rent(&tenant, &String::from_str(&env, "agreement_5"), &1000);
+    assert_eq!(result, Err(Ok(PaymentError::NotInitialized)));
+
+    payment.initialize(&Address::generate(&env), &Address::generate(&env));
+    let result = payment.try_initialize(&Address::generate(&env), &Address::generate(&env));
+    assert_eq!(result, Err(Ok(PaymentError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_admin_changes_chioma_contract() {
+    let env = Env::default();
+    let payment = PaymentContractClient::new(&env, &env.register(PaymentContract, ()));
+    let admin = Address::generate(&env);
+    let chioma = Address::generate(&env);
+
+    let result = payment.try_set_chioma_contract(&chioma);
+    assert_eq!(result, Err(Ok(PaymentError::NotInitialized)));
+
+    let old_chioma = Address::generate(&env);
+    payment.mock_all_auths().initialize(&admin, &old_chioma);
+    payment.mock_all_auths().set_chioma_contract(&chioma);
+    assert_eq!(env.auths()[0].0, admin);
+
+    // Topics: ["chioma_set", admin]; data carries the old and new addresses
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    let (_, topics, data) = events.last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "chioma_set"));
+    let topic_admin: Address = topics.get(1).unwrap().into_val(&env);
+    assert_eq!(topic_admin, admin);
+    let data: Map<Symbol, Address> = data.into_val(&env);
+    assert_eq!(
+        data.get(Symbol::new(&env, "old_contract")),
+        Some(old_chioma)
+    );
+    assert_eq!(
+        data.get(Symbol::new(&env, "new_contract")),
+        Some(chioma.clone())
+    );
+    assert_eq!(payment.get_chioma_contract(), Some(chioma));
+
+    // Without the admin's signature the address cannot be changed
+    assert!(payment
+        .try_set_chioma_contract(&Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_payment_ledger_totals_and_paging() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (payment, chioma) = setup_payment(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+    TokenAdminClient::new(&env, &token).mint(&tenant, &100_000);
+    chioma.set_config(&1000, &Address::generate(&env));
+
+    for id in ["agreement_6", "agreement_7"] {
+        let mut agreement = create_test_agreement(
+            &env,
+            id,
+            &tenant,
+            &landlord,
+            None,
+            1000,
+            0,
+            AgreementStatus::Active,
+            token.clone(),
+        );
+        agreement.platform_fee_bps = 1000;
+        chioma.set_agreement(&agreement);
+    }
+    let first = String::from_str(&env, "agreement_6");
+    let second = String::from_str(&env, "agreement_7");
+
+    // Twelve monthly payments on the first agreement, one on the second
+    for month in 0..12u64 {
+        env.ledger().with_mut(|li| li.timestamp = month * 2_592_000);
+        payment.pay_rent(&tenant, &first, &1000);
+    }
+    payment.pay_rent(&tenant, &second, &1000);
+
+    assert_eq!(payment.get_total_paid(&first), 12_000);
+    assert_eq!(payment.get_total_paid(&second), 1000);
+    assert_eq!(payment.get_payment_count(), 13);
+
+    let ledger = payment.get_payment_ledger(&first);
+    assert_eq!(ledger.payment_count, 12);
+    assert_eq!(ledger.landlord_paid, 10_800);
+    assert_eq!(ledger.platform_paid, 1200);
+    assert_eq!(ledger.last_payment_at, 11 * 2_592_000);
+
+    let twelfth = payment.get_payment(&first, &12);
+    assert_eq!(twelfth.payment_number, 12);
+    assert_eq!(twelfth.amount, 1000);
+    assert_eq!(
+        payment.try_get_payment(&first, &13),
+        Err(Ok(PaymentError::PaymentNotFound))
+    );
+
+    let page = payment.get_payments_by_agreement(&first, &10, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().payment_number, 11);
+    assert_eq!(page.get(1).unwrap().payment_number, 12);
+
+    let page = payment.get_payments_by_tenant(&tenant, &11, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().agreement_id, first);
+    assert_eq!(page.get(1).unwrap().agreement_id, second);
+    assert_eq!(page.get(1).unwrap().payment_number, 1);
+
+    assert_eq!(payment.get_payments_by_tenant(&landlord, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_pay_rent_pays_agent_commission_and_agreement_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (payment, chioma) = setup_payment(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+    TokenAdminClient::new(&env, &token).mint(&tenant, &5000);
+
+    // 2.5% platform fee, 3.33% agent commission on 999: both shares round down.
+    // The fee fixed on the agreement applies even though the config has since changed.
+    chioma.set_config(&9_000, &collector);
+    let mut agreement = create_test_agreement(
+        &env,
+        "agreement_8",
+        &tenant,
+        &landlord,
+        Some(agent.clone()),
+        999,
+        333,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    agreement.platform_fee_bps = 250;
+    chioma.set_agreement(&agreement);
+
+    let agreement_id = String::from_str(&env, "agreement_8");
+    payment.pay_rent(&tenant, &agreement_id, &999);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&agent), 33);
+    assert_eq!(token_client.balance(&collector), 24);
+    assert_eq!(token_client.balance(&landlord), 942);
+
+    let split = payment.get_payment_split(&agreement_id, &0);
+    assert_eq!(
+        (
+            split.landlord_amount,
+            split.agent_amount,
+            split.platform_amount
+        ),
+        calculate_payment_split(&999, &333, &250).unwrap()
+    );
+
+    let ledger = payment.get_payment_ledger(&agreement_id);
+    assert_eq!(ledger.agent_paid, 33);
+    assert_eq!(ledger.platform_paid, 24);
+}
+
+#[test]
+fn test_pay_rent_without_agent_keeps_commission_with_landlord() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (payment, chioma) = setup_payment(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+    TokenAdminClient::new(&env, &token).mint(&tenant, &5000);
+
+    chioma.set_config(&0, &collector);
+    chioma.set_agreement(&create_test_agreement(
+        &env,
+        "agreement_9",
+        &tenant,
+        &landlord,
+        None,
+        1000,
+        500,
+        AgreementStatus::Active,
+        token.clone(),
+    ));
+
+    payment.pay_rent(&tenant, &String::from_str(&env, "agreement_9"), &1000);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&landlord), 1000);
+    assert_eq!(token_client.balance(&collector), 0);
+}
+
+#[test]
+fn test_pay_rent_charges_late_fee_to_landlord() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (payment, chioma) = setup_payment(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+    TokenAdminClient::new(&env, &token).mint(&tenant, &5000);
+
+    chioma.set_config(&0, &collector);
+    let mut agreement = create_test_agreement(
+        &env,
+        "agreement_10",
+        &tenant,
+        &landlord,
+        Some(agent.clone()),
+        1000,
+        1000,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    agreement.platform_fee_bps = 100;
+    agreement.late_fee_bps = 500;
+    agreement.late_fee_grace_period = 86_400;
+    chioma.set_agreement(&agreement);
+    let agreement_id = String::from_str(&env, "agreement_10");
+
+    // Within the grace period no fee is due
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    assert_eq!(late_fee(&agreement, 86_400), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 86_401);
+    let result = payment.try_pay_rent(&tenant, &agreement_id, &1000);
+    assert_eq!(result, Err(Ok(PaymentError::InvalidPaymentAmount)));
+    payment.pay_rent(&tenant, &agreement_id, &1050);
+
+    // Commission and fee apply to the rent only; the late fee goes to the landlord
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&agent), 100);
+    assert_eq!(token_client.balance(&collector), 10);
+    assert_eq!(token_client.balance(&landlord), 940);
+
+    let ledger = payment.get_payment_ledger(&agreement_id);
+    assert_eq!(ledger.total_paid, 1050);
+    assert_eq!(ledger.platform_paid, 10);
+}
+
+/// Deploy the real chioma contract with a 1% platform fee, wired to a fresh
+/// payment contract, and an Active agreement paying 1000 a period
+fn setup_with_chioma(
+    env: &Env,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+    token: &Address,
+) -> (
+    PaymentContractClient<'static>,
+    chioma::ContractClient<'static>,
+) {
+    let chioma_client = chioma::ContractClient::new(env, &env.register(chioma::Contract, ()));
+    let payment_id = env.register(PaymentContract, ());
+    let payment = PaymentContractClient::new(env, &payment_id);
+
+    let admin = Address::generate(env);
+    chioma_client.initialize(
+        &admin,
+        &chioma::Config {
+            fee_bps: 100,
+            fee_collector: admin.clone(),
+            paused: false,
+        },
+    );
+    chioma_client.set_payment_contract(&payment_id);
+    payment.initialize(&admin, &chioma_client.address);
+
+    let id = String::from_str(env, agreement_id);
+    chioma_client.create_agreement(
+        &id,
+        landlord,
+        tenant,
+        &None,
+        &1000,
+        &2000,
+        &0,
+        &(12 * 2_592_000),
+        &0,
+        token,
+    );
+    chioma_client.submit_agreement(landlord, &id);
+    chioma_client.sign_agreement(tenant, &id);
+
+    (payment, chioma_client)
+}
+
+#[test]
+fn test_pay_rent_against_chioma_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+    TokenAdminClient::new(&env, &token).mint(&tenant, &5000);
+    let (payment, chioma_client) = setup_with_chioma(&env, "chioma_1", &tenant, &landlord, &token);
+    let agreement_id = String::from_str(&env, "chioma_1");
+
+    // The mirrored agreement and config types decode chioma's own values
+    let (agreement, config) = env.as_contract(&payment.address, || {
+        (
+            load_agreement(&env, &agreement_id).unwrap(),
+            load_config(&env).unwrap(),
+        )
+    });
+    assert_eq!(agreement.status, AgreementStatus::Active);
+    assert_eq!(agreement.platform_fee_bps, 100);
+    assert_eq!(config.fee_bps, 100);
+
+    payment.pay_rent(&tenant, &agreement_id, &1000);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&landlord), 990);
+    let recorded = chioma_client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(recorded.payment_count, 1);
+    assert_eq!(recorded.total_rent_paid, 1000);
+    assert_eq!(recorded.next_payment_due, 2_592_000);
+    assert_eq!(
+        chioma_client
+            .get_statement(&agreement_id)
+            .outstanding_balance,
+        0
+    );
+
+    // A rejection from chioma comes back as a payment error instead of a trap
+    env.ledger().with_mut(|li| li.timestamp = 2_592_000);
+    chioma_client.update_config(&chioma::Config {
+        fee_bps: 100,
+        fee_collector: landlord.clone(),
+        paused: true,
+    });
+    let result = payment.try_pay_rent(&tenant, &agreement_id, &1000);
+    assert_eq!(result, Err(Ok(PaymentError::ChiomaCallFailed)));
+    assert_eq!(token_client.balance(&tenant), 4000);
+}
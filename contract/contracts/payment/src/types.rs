@@ -14,14 +14,28 @@ pub struct PaymentRecord {
     pub tenant: Address,
 }
 
+/// Running totals of the payments made on one agreement
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PaymentLedger {
+    pub payment_count: u32,
+    pub total_paid: i128,
+    pub landlord_paid: i128,
+    pub agent_paid: i128,
+    pub platform_paid: i128,
+    pub last_payment_at: u64,
+}
+
 /// Payment split information for rent payments
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentSplit {
     pub landlord_amount: i128,
+    pub agent_amount: i128,
     pub platform_amount: i128,
     pub token: Address,
     pub payment_date: u64,
+    pub payer: Address,
 }
 
 /// Agreement status enum (needed for payment validation)
@@ -35,9 +49,11 @@ pub enum AgreementStatus {
     Cancelled,
     Terminated,
     Disputed,
+    Holdover,
 }
 
-/// Rent agreement structure (needed for payment processing)
+/// Rent agreement as stored by the chioma contract; the fields must match chioma's
+/// `RentAgreement` exactly for cross-contract calls to decode it
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RentAgreement {
@@ -49,7 +65,10 @@ pub struct RentAgreement {
     pub security_deposit: i128,
     pub start_date: u64,
     pub end_date: u64,
+    /// Agent commission in basis points of each rent payment
     pub agent_commission_rate: u32,
+    /// Platform fee in basis points of each rent payment, fixed at creation
+    pub platform_fee_bps: u32,
     pub status: AgreementStatus,
     pub total_rent_paid: i128,
     pub payment_count: u32,
@@ -57,4 +76,33 @@ pub struct RentAgreement {
     pub payment_token: Address,
     pub next_payment_due: u64,
     pub payment_history: Map<u32, PaymentSplit>,
+    pub late_fee_bps: u32,
+    pub late_fee_grace_period: u64,
+    pub terms_hash: Option<String>,
+    pub template_id: Option<String>,
+    pub deposit_released_at: Option<u64>,
+    /// Holdover rent as basis points of `monthly_rent`; zero without a holdover clause
+    pub holdover_rent_bps: u32,
+    pub holdover_notice_period: u64,
+    pub holdover_started_at: Option<u64>,
+    pub holdover_ends_at: Option<u64>,
+}
+
+/// Chioma's platform configuration; the fields must match chioma's `Config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Platform fee in basis points of each rent payment
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+    pub paused: bool,
+}
+
+/// Chioma's contract state, read for its platform configuration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractState {
+    pub admin: Address,
+    pub config: Config,
+    pub initialized: bool,
 }